@@ -7,15 +7,15 @@
 /// issue is that we are not allowed to get references to objects which may contain [`UnsafeCell`]s
 /// -- both `&self` and `&self.xs` are impossible. This is used to do operations such as remove,
 /// remove_swap, or pop, which would usually require a reference to `self.xs` at least in order to
-/// copy its contents onto the stack. Thus if T contains an `UnsafeCell` this approach probably
-/// isn't allowed, or maybe it just isn't allowed at all because of the strange things we do with a
-/// zst pointer.
+/// copy its contents onto the stack. Instead the item is read through a raw pointer to `self.xs`,
+/// which never creates a reference. If T contains an `UnsafeCell` this approach may still not be
+/// allowed.
 ///
 /// And of course, since this function performs a copy of a non-copy type, you need to make sure
 /// that *the element at this index is never accessed as a `T` again*.
 macro_rules! copy_item {
     ($self:ident<$item_type:ty>[$ix:expr]) => {{
-        // we can't get a pointer to xs or self, but we can get one to a zst with the same address
+        // we can't get a reference to xs or self, but a raw pointer to xs is fine
         let ptr_to_xs = crate::addressing::extract_addr!($self<$item_type>);
         // we have a pointer to our array now, but we need a pointer to the item's location
         let ptr_to_elem = ptr_to_xs.add($ix);
@@ -24,26 +24,9 @@ macro_rules! copy_item {
     }};
 }
 
-#[repr(C)]
-pub struct AddressExtractor<T, const N: usize> {
-    pub xs_addr: (),
-    #[allow(dead_code)]
-    xs: [T; N],
-}
-
-impl<T, const N: usize> AddressExtractor<T, N> {
-    pub const fn new(arr: [T; N]) -> Self {
-        Self {
-            xs: arr,
-            xs_addr: (),
-        }
-    }
-}
-
-
 macro_rules! extract_addr {
     ($self:ident<$item_type:ty>) => {
-        ::core::ptr::addr_of!($self.xs_addr) as *const $item_type
+        ::core::ptr::addr_of!($self.xs) as *const $item_type
     };
 }
 
@@ -1,39 +1,31 @@
-use crate::{
-    addressing::{extract_addr, AddressExtractor},
-    assertions, MaybeUninit,
-};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr::{addr_of, addr_of_mut};
+
+use crate::assertions;
 
 pub(crate) const fn extend_uninit_array<T, const N: usize, const CAP: usize>(
     xs: [MaybeUninit<T>; N],
 ) -> [MaybeUninit<T>; CAP] {
-    let addressor = AddressExtractor::new(xs);
-    let address = extract_addr!(addressor<MaybeUninit<T>>);
-    let copy = copy_from_raw::<_, N, CAP>(address);
-    core::mem::forget(addressor);
-    copy
+    // `MaybeUninit` has no drop glue, so the source array can simply go out of scope
+    copy_from_raw::<_, N, CAP>(addr_of!(xs) as *const MaybeUninit<T>)
 }
 
 pub(crate) const fn extend_array<T, const N: usize, const CAP: usize>(
     xs: [T; N],
 ) -> [MaybeUninit<T>; CAP] {
-    let addressor = AddressExtractor::new(xs);
-    let address = extract_addr!(addressor<MaybeUninit<T>>);
-    let copy = copy_from_raw::<_, N, CAP>(address);
-    core::mem::forget(addressor);
-    copy
+    // the elements are moved into the new buffer, so they mustn't be dropped here as well
+    let xs = ManuallyDrop::new(xs);
+    copy_from_raw::<_, N, CAP>(addr_of!(xs) as *const MaybeUninit<T>)
 }
 
 const fn copy_from_raw<T, const N: usize, const CAP: usize>(
     xs: *const MaybeUninit<T>,
 ) -> [MaybeUninit<T>; CAP] {
     assertions::Leq::<N, CAP>::assert();
-    let mut buffer: [MaybeUninit<T>; CAP] = unsafe { MaybeUninit::uninit().assume_init() };
-
-    let mut ix = 0;
-    while ix < N {
-        buffer[ix] = unsafe { xs.add(ix).read() };
-        ix += 1;
+    let mut buffer = [const { MaybeUninit::uninit() }; CAP];
+    // copy the whole block at once, rather than an element at a time
+    unsafe {
+        core::ptr::copy_nonoverlapping(xs, addr_of_mut!(buffer) as *mut MaybeUninit<T>, N);
     }
-
     buffer
 }
@@ -2,7 +2,11 @@
 pub struct Leq<const LESSER: usize, const GREATER: usize>;
 
 impl<const LESSER: usize, const GREATER: usize> Leq<LESSER, GREATER> {
-    const CHECK: () = assert!(LESSER <= GREATER);
+    // the values can't be formatted in const, but the error names them through `Leq::<N, CAP>`
+    const CHECK: () = assert!(
+        LESSER <= GREATER,
+        "the number of elements exceeds the vector's capacity"
+    );
 
     pub const fn assert() -> Self {
         #[allow(clippy::let_unit_value)]
@@ -0,0 +1,37 @@
+use bytemuck::{Pod, PodCastError};
+
+use crate::ConstVec;
+
+/// Returned when bytes can't be turned into a [`ConstVec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromBytesError {
+    /// The bytes are misaligned for the element type, or their length isn't a multiple of its size.
+    Cast(PodCastError),
+    /// The bytes hold more elements than the vector's capacity.
+    Capacity,
+}
+
+impl core::fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Cast(e) => write!(f, "could not cast bytes: {e}"),
+            Self::Capacity => write!(f, "capacity exceeded"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromBytesError {}
+
+impl<T: Pod, const CAP: usize> ConstVec<T, CAP> {
+    /// Views the live elements as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    /// Copies the elements held by `bytes` into a new vector. `bytes` must be aligned for `T`.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        let xs = bytemuck::try_cast_slice(bytes).map_err(FromBytesError::Cast)?;
+        Self::try_from_slice(xs).map_err(|_| FromBytesError::Capacity)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::ConstVec;
+
+impl<const CAP: usize> ConstVec<u8, CAP> {
+    /// Copies the UTF-8 bytes of `s` into a new vector. Panics if `s` is longer than `CAP`, which
+    /// fails to compile in a const.
+    pub const fn from_str(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        if bytes.len() > CAP {
+            panic!("the string doesn't fit in the capacity")
+        }
+
+        let mut vector = Self::new();
+        while vector.len < bytes.len() {
+            let byte = bytes[vector.len];
+            vector = unsafe { vector.push_unchecked(byte) };
+        }
+        vector
+    }
+
+    /// The live elements as a string, or an error if they aren't valid UTF-8.
+    pub const fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(self.as_slice())
+    }
+
+    /// # Safety
+    ///
+    /// The live elements must be valid UTF-8.
+    pub const unsafe fn as_str_unchecked(&self) -> &str {
+        core::str::from_utf8_unchecked(self.as_slice())
+    }
+}
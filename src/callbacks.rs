@@ -0,0 +1,344 @@
+// Methods which take a function or compare elements. Plain function pointers can't be called at
+// const-time, so these are generic over `[const]` traits instead.
+
+#[cfg(feature = "fake-move")]
+use core::{cmp::Ordering, mem::MaybeUninit};
+
+#[cfg(feature = "fake-move")]
+use crate::addressing;
+use crate::ConstVec;
+
+impl<T, const CAP: usize> ConstVec<T, CAP> {
+    /// Creates a vector of `N` elements, calling `f` with each index in turn, like
+    /// [`core::array::from_fn`]. Fails to compile if `N` is greater than `CAP`.
+    pub const fn from_fn<const N: usize, F>(f: F) -> Self
+    where
+        F: [const] Fn(usize) -> T + Copy,
+    {
+        Self::assert_fits::<N>();
+        let mut vector = Self::new();
+        while vector.len < N {
+            let item = f(vector.len);
+            vector = unsafe { vector.push_unchecked(item) };
+        }
+        vector
+    }
+
+    /// Const counterpart to comparing the vector with a slice through [`PartialEq`].
+    pub const fn eq_slice(&self, other: &[T]) -> bool
+    where
+        T: [const] PartialEq,
+    {
+        if self.len != other.len() {
+            return false;
+        }
+
+        let mut ix = 0;
+        while ix < self.len {
+            if self.as_slice()[ix] != other[ix] {
+                return false;
+            }
+            ix += 1;
+        }
+        true
+    }
+
+    /// Combines the elements in order, starting from `init`, like [`Iterator::fold`].
+    pub const fn fold<A, F>(&self, init: A, f: F) -> A
+    where
+        F: [const] Fn(A, &T) -> A + Copy,
+    {
+        let xs = self.as_slice();
+        let mut acc = init;
+        let mut ix = 0;
+        while ix < xs.len() {
+            acc = f(acc, &xs[ix]);
+            ix += 1;
+        }
+        acc
+    }
+
+    /// Whether every element satisfies `pred`, stopping at the first which doesn't. True for an
+    /// empty vector.
+    pub const fn all<F>(&self, pred: F) -> bool
+    where
+        F: [const] Fn(&T) -> bool + Copy,
+    {
+        let xs = self.as_slice();
+        let mut ix = 0;
+        while ix < xs.len() {
+            if !pred(&xs[ix]) {
+                return false;
+            }
+            ix += 1;
+        }
+        true
+    }
+
+    /// Whether any element satisfies `pred`, stopping at the first which does. False for an empty
+    /// vector.
+    pub const fn any<F>(&self, pred: F) -> bool
+    where
+        F: [const] Fn(&T) -> bool + Copy,
+    {
+        let xs = self.as_slice();
+        let mut ix = 0;
+        while ix < xs.len() {
+            if pred(&xs[ix]) {
+                return true;
+            }
+            ix += 1;
+        }
+        false
+    }
+
+    /// The number of elements at the front of the vector which satisfy `pred`.
+    pub const fn count_leading<F>(&self, pred: F) -> usize
+    where
+        F: [const] Fn(&T) -> bool + Copy,
+    {
+        let xs = self.as_slice();
+        let mut count = 0;
+        while count < xs.len() && pred(&xs[count]) {
+            count += 1;
+        }
+        count
+    }
+
+    /// The number of elements at the back of the vector which satisfy `pred`.
+    pub const fn count_trailing<F>(&self, pred: F) -> usize
+    where
+        F: [const] Fn(&T) -> bool + Copy,
+    {
+        let xs = self.as_slice();
+        let mut count = 0;
+        while count < xs.len() && pred(&xs[xs.len() - 1 - count]) {
+            count += 1;
+        }
+        count
+    }
+
+    /// Const counterpart to [`ConstVec::dedup`] for `Copy` types, collapsing each run of equal
+    /// elements into its first.
+    pub const fn dedup_consecutive(self) -> Self
+    where
+        T: Copy + [const] PartialEq,
+    {
+        let xs = self.as_slice();
+        let mut deduped = Self::new();
+        let mut ix = 0;
+        while ix < xs.len() {
+            if ix == 0 || xs[ix] != xs[ix - 1] {
+                deduped = unsafe { deduped.push_unchecked(xs[ix]) };
+            }
+            ix += 1;
+        }
+        core::mem::forget(self);
+        deduped
+    }
+
+    /// The greatest element, or the last of them if several are equally great. Named so that it
+    /// doesn't clash with [`Ord::max`] on the vector itself.
+    pub const fn max_element(&self) -> Option<&T>
+    where
+        T: [const] Ord,
+    {
+        let xs = self.as_slice();
+        if xs.is_empty() {
+            return None;
+        }
+
+        let mut max = &xs[0];
+        let mut ix = 1;
+        while ix < xs.len() {
+            if xs[ix].cmp(max).is_ge() {
+                max = &xs[ix];
+            }
+            ix += 1;
+        }
+        Some(max)
+    }
+
+    /// The least element, or the first of them if several are equally small.
+    pub const fn min_element(&self) -> Option<&T>
+    where
+        T: [const] Ord,
+    {
+        let xs = self.as_slice();
+        if xs.is_empty() {
+            return None;
+        }
+
+        let mut min = &xs[0];
+        let mut ix = 1;
+        while ix < xs.len() {
+            if xs[ix].cmp(min).is_lt() {
+                min = &xs[ix];
+            }
+            ix += 1;
+        }
+        Some(min)
+    }
+
+    /// Const counterpart to [`slice::binary_search`] on a sorted vector. Returns the index of a
+    /// matching element, or the index where `target` could be inserted to keep the vector sorted.
+    pub const fn binary_search_const(&self, target: &T) -> Result<usize, usize>
+    where
+        T: [const] Ord,
+    {
+        let xs = self.as_slice();
+        let mut low = 0;
+        let mut high = xs.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let ordering = xs[mid].cmp(target);
+            if ordering.is_lt() {
+                low = mid + 1;
+            } else if ordering.is_gt() {
+                high = mid;
+            } else {
+                return Ok(mid);
+            }
+        }
+        Err(low)
+    }
+
+    /// Const counterpart to [`slice::is_sorted`], which it shadows with the same bound: whether
+    /// each element is less than or equal to the next.
+    pub const fn is_sorted(&self) -> bool
+    where
+        T: [const] PartialOrd,
+    {
+        let xs = self.as_slice();
+        let mut ix = 1;
+        while ix < xs.len() {
+            if !xs[ix - 1].le(&xs[ix]) {
+                return false;
+            }
+            ix += 1;
+        }
+        true
+    }
+
+    /// Applies `f` to each element, collecting the results into a vector of the same capacity.
+    #[cfg(feature = "fake-move")]
+    pub const fn map<U, F>(mut self, f: F) -> ConstVec<U, CAP>
+    where
+        F: [const] Fn(T) -> U + Copy,
+    {
+        let len = self.len;
+        // if `f` panics, the elements which haven't been moved out yet are leaked, not dropped twice
+        self = unsafe { self.set_len(0) };
+
+        let mut mapped = ConstVec::new();
+        let mut ix = 0;
+        while ix < len {
+            unsafe {
+                let item = addressing::copy_item!(self<T>[ix]);
+                mapped = mapped.push_unchecked(f(item));
+            }
+            ix += 1;
+        }
+        core::mem::forget(self);
+        mapped
+    }
+
+    /// Moves the elements which satisfy `pred` into the first vector and the rest into the second,
+    /// keeping their order.
+    #[cfg(feature = "fake-move")]
+    pub const fn partition<F>(mut self, pred: F) -> (Self, Self)
+    where
+        F: [const] Fn(&T) -> bool + Copy,
+    {
+        let len = self.len;
+        // if `pred` panics, the elements which haven't been moved out yet are leaked, not dropped
+        // twice
+        self = unsafe { self.set_len(0) };
+
+        let mut matching = Self::new();
+        let mut rest = Self::new();
+        let mut ix = 0;
+        while ix < len {
+            unsafe {
+                let item = addressing::copy_item!(self<T>[ix]);
+                if pred(&item) {
+                    matching = matching.push_unchecked(item);
+                } else {
+                    rest = rest.push_unchecked(item);
+                }
+            }
+            ix += 1;
+        }
+        core::mem::forget(self);
+        (matching, rest)
+    }
+
+    /// Removes the elements at the front of the vector which satisfy `pred`, shifting the rest down.
+    /// Destructors can't run at const-time, so the removed elements are leaked.
+    #[cfg(feature = "fake-move")]
+    pub const fn trim_leading<F>(mut self, pred: F) -> Self
+    where
+        F: [const] Fn(&T) -> bool + Copy,
+    {
+        let trimmed = self.count_leading(pred);
+        if trimmed == 0 {
+            return self;
+        }
+
+        let len = self.len;
+        let mut ix = trimmed;
+        while ix < len {
+            unsafe {
+                let item = addressing::copy_item!(self<MaybeUninit<T>>[ix]);
+                self.xs[ix - trimmed] = item;
+            }
+            ix += 1;
+        }
+        unsafe { self.set_len(len - trimmed) }
+    }
+
+    /// Removes the elements at the back of the vector which satisfy `pred`. Like
+    /// [`ConstVec::trim_leading`], the removed elements are leaked.
+    #[cfg(feature = "fake-move")]
+    pub const fn trim_trailing<F>(self, pred: F) -> Self
+    where
+        F: [const] Fn(&T) -> bool + Copy,
+    {
+        let len = self.len - self.count_trailing(pred);
+        unsafe { self.set_len(len) }
+    }
+
+    /// Sorts the vector with an insertion sort, ordering elements by `compare`. The sort is
+    /// stable, so equal elements keep their relative order.
+    #[cfg(feature = "fake-move")]
+    pub const fn sorted_by<F>(mut self, compare: F) -> Self
+    where
+        F: [const] Fn(&T, &T) -> Ordering + Copy,
+    {
+        let len = self.len;
+        // if `compare` panics, the vector is left with a hole in it, so leak the elements instead
+        self = unsafe { self.set_len(0) };
+
+        let mut sorted = 1;
+        while sorted < len {
+            unsafe {
+                let item = addressing::copy_item!(self<T>[sorted]);
+                // shift every element which belongs after `item` up by one to make room for it
+                let mut hole = sorted;
+                while hole > 0 {
+                    let prev = &*addressing::extract_addr!(self<T>).add(hole - 1);
+                    if compare(&item, prev).is_lt() {
+                        let shifting = addressing::copy_item!(self<MaybeUninit<T>>[hole - 1]);
+                        self.xs[hole] = shifting;
+                        hole -= 1;
+                    } else {
+                        break;
+                    }
+                }
+                self.xs[hole] = MaybeUninit::new(item);
+            }
+            sorted += 1;
+        }
+        unsafe { self.set_len(len) }
+    }
+}
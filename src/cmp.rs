@@ -0,0 +1,57 @@
+use crate::ConstVec;
+
+impl<T, const A: usize, const B: usize> PartialEq<ConstVec<T, B>> for ConstVec<T, A>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &ConstVec<T, B>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const CAP: usize> Eq for ConstVec<T, CAP> {}
+
+impl<T: PartialEq, const CAP: usize> PartialEq<[T]> for ConstVec<T, CAP> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const CAP: usize, const N: usize> PartialEq<[T; N]> for ConstVec<T, CAP> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const CAP: usize> PartialEq<ConstVec<T, CAP>> for [T] {
+    fn eq(&self, other: &ConstVec<T, CAP>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const CAP: usize, const N: usize> PartialEq<ConstVec<T, CAP>> for [T; N] {
+    fn eq(&self, other: &ConstVec<T, CAP>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T, const A: usize, const B: usize> PartialOrd<ConstVec<T, B>> for ConstVec<T, A>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &ConstVec<T, B>) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const CAP: usize> Ord for ConstVec<T, CAP> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: core::hash::Hash, const CAP: usize> core::hash::Hash for ConstVec<T, CAP> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
@@ -1,8 +1,130 @@
-use crate::{ConstVec, MaybeUninit};
+use core::mem::MaybeUninit;
+use core::ptr::addr_of;
 
+use crate::{CapacityError, ConstVec};
+
+/// Borrowing iterator over a vector, returned by [`ConstVec::iter`]. It supports everything
+/// `core::slice::Iter` does, and can also be driven at const-time. Its `size_hint` is always exact.
 pub struct ConstVecIter<'a, T, const N: usize> {
     vec: &'a ConstVec<T, N>,
     ix: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> ConstVec<T, N> {
+    pub const fn iter(&self) -> ConstVecIter<'_, T, N> {
+        ConstVecIter {
+            vec: self,
+            ix: 0,
+            end: self.len,
+        }
+    }
+
+    /// The index of the first element matching `pred`.
+    pub fn position<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().position(pred)
+    }
+
+    /// The number of elements matching `pred`.
+    pub fn count<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().filter(|x| pred(x)).count()
+    }
+
+    /// Collects an iterator into a vector. If the iterator yields more than `N` items, the error
+    /// holds the filled vector and the first item which didn't fit. Pass the iterator by reference
+    /// to keep hold of the items after that.
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, CapacityError<T, N>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut vector = Self::new();
+        for item in iter {
+            vector = vector.try_push(item)?;
+        }
+        Ok(vector)
+    }
+
+    /// Collects at most `N` items from an iterator into a vector. The iterator isn't advanced past
+    /// the items which fit, so nothing is pulled out of it only to be thrown away.
+    pub fn from_iter_saturating<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut vector = Self::new();
+        vector.extend(iter.into_iter().take(N));
+        vector
+    }
+}
+
+/// Panics if the iterator yields more items than the vector's capacity. Use
+/// [`ConstVec::try_from_iter`] to handle this case instead.
+impl<T, const N: usize> FromIterator<T> for ConstVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        match Self::try_from_iter(iter) {
+            Ok(vector) => vector,
+            Err(_) => panic!("iterator yielded more than {N} items"),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ConstVecIter<'a, T, N> {
+    /// Const counterpart to [`Iterator::next`], so a borrowed vector can be walked at const-time.
+    pub const fn next_const(&mut self) -> Option<&'a T> {
+        if self.ix < self.end {
+            let item = &self.vec.as_slice()[self.ix];
+            self.ix += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    /// The elements which haven't been yielded yet, like `core::slice::Iter::as_slice`.
+    pub const fn as_slice(&self) -> &'a [T] {
+        let (_, rest) = self.vec.as_slice().split_at(self.ix);
+        let (remaining, _) = rest.split_at(self.end - self.ix);
+        remaining
+    }
+
+    /// Const counterpart to [`DoubleEndedIterator::next_back`].
+    pub const fn next_back_const(&mut self) -> Option<&'a T> {
+        if self.ix < self.end {
+            self.end -= 1;
+            Some(&self.vec.as_slice()[self.end])
+        } else {
+            None
+        }
+    }
+}
+
+// derives would require `T: Clone` and `T: Debug` of the vector's elements for no reason
+impl<T, const N: usize> Clone for ConstVecIter<'_, T, N> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for ConstVecIter<'_, T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ConstVecIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+/// Panics if the iterator yields more items than the vector has room for.
+impl<T, const N: usize> Extend<T> for ConstVec<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_mut(item);
+        }
+    }
 }
 
 impl<'a, T, const N: usize> IntoIterator for &'a ConstVec<T, N> {
@@ -15,26 +137,43 @@ impl<'a, T, const N: usize> IntoIterator for &'a ConstVec<T, N> {
     }
 }
 
+impl<'a, T, const N: usize> IntoIterator for &'a mut ConstVec<T, N> {
+    type Item = &'a mut T;
+
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 impl<'a, T, const N: usize> Iterator for ConstVecIter<'a, T, N> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        use core::ops::Deref;
-        if self.ix < self.vec.len {
-            let res = unsafe { &self.vec.xs[self.ix].value }.deref();
-            self.ix += 1;
-            Some(res)
-        } else {
-            None
-        }
+        self.next_const()
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.vec.len - self.ix;
+        let len = self.end - self.ix;
         (len, Some(len))
     }
 }
 
+impl<'a, T, const N: usize> DoubleEndedIterator for ConstVecIter<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_back_const()
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for ConstVecIter<'a, T, N> {}
+
+impl<'a, T, const N: usize> core::iter::FusedIterator for ConstVecIter<'a, T, N> {}
+
+#[cfg(feature = "trusted-len")]
+unsafe impl<'a, T, const N: usize> core::iter::TrustedLen for ConstVecIter<'a, T, N> {}
+
+/// Owning iterator over a vector. Its `size_hint` is always exact.
 pub struct ConstVecIntoIter<T, const CAP: usize> {
     xs: [MaybeUninit<T>; CAP],
     ix: usize,
@@ -47,11 +186,27 @@ impl<T, const CAP: usize> IntoIterator for ConstVec<T, CAP> {
     type IntoIter = ConstVecIntoIter<T, CAP>;
 
     fn into_iter(self) -> Self::IntoIter {
-        ConstVecIntoIter {
-            xs: self.xs,
-            ix: 0,
-            len: self.len,
-        }
+        let (len, xs) = self.into_raw_parts();
+        ConstVecIntoIter { xs, ix: 0, len }
+    }
+}
+
+impl<T, const CAP: usize> ConstVecIntoIter<T, CAP> {
+    /// The elements which haven't been yielded yet.
+    pub fn as_slice(&self) -> &[T] {
+        let remaining = unsafe { (addr_of!(self.xs) as *const T).add(self.ix) };
+        unsafe { core::slice::from_raw_parts(remaining, self.len - self.ix) }
+    }
+}
+
+/// Only the elements which haven't been yielded yet are cloned.
+impl<T: Clone, const CAP: usize> Clone for ConstVecIntoIter<T, CAP> {
+    fn clone(&self) -> Self {
+        self.as_slice()
+            .iter()
+            .cloned()
+            .collect::<ConstVec<T, CAP>>()
+            .into_iter()
     }
 }
 
@@ -60,9 +215,9 @@ impl<T, const CAP: usize> Iterator for ConstVecIntoIter<T, CAP> {
 
     fn next(&mut self) -> Option<Self::Item> {
         (self.ix < self.len).then(|| {
-            let item = core::mem::replace(&mut self.xs[self.ix], MaybeUninit::uninit());
+            let item = unsafe { self.xs[self.ix].assume_init_read() };
             self.ix += 1;
-            unsafe { item.assume_init() }
+            item
         })
     }
 
@@ -72,6 +227,22 @@ impl<T, const CAP: usize> Iterator for ConstVecIntoIter<T, CAP> {
     }
 }
 
+impl<T, const CAP: usize> Drop for ConstVecIntoIter<T, CAP> {
+    fn drop(&mut self) {
+        // elements before `ix` have already been yielded
+        for ix in self.ix..self.len {
+            unsafe { self.xs[ix].assume_init_drop() };
+        }
+    }
+}
+
+impl<T, const CAP: usize> ExactSizeIterator for ConstVecIntoIter<T, CAP> {}
+
+impl<T, const CAP: usize> core::iter::FusedIterator for ConstVecIntoIter<T, CAP> {}
+
+#[cfg(feature = "trusted-len")]
+unsafe impl<T, const CAP: usize> core::iter::TrustedLen for ConstVecIntoIter<T, CAP> {}
+
 impl<T, const CAP: usize> core::fmt::Debug for ConstVec<T, CAP>
 where
     T: core::fmt::Debug,
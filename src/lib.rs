@@ -2,90 +2,306 @@
 //!
 //! Removing or swapping elements needs the crate feature `fake-move`, which depends on the lang
 //! feature `const_ptr_read`. This is stable in the nightly rust version `1.71.0`.
+//!
+//! `ConstVec` drops its elements like any other collection, but destructors can't be run at
+//! const-time. A const fn must therefore hand every `ConstVec` it creates back out or pass it to
+//! [`core::mem::forget`]. Destructuring the `(ConstVec, _)` pairs returned by methods like `pop`
+//! inside a const fn additionally needs the lang feature `const_precise_live_drops`.
+//!
+//! Methods which take a function or compare elements at const-time, like `map` and `eq_slice`, need
+//! the crate feature `const-callbacks`, which depends on the features `const_trait_impl` and
+//! `const_cmp`. Calling them at const-time needs `const_trait_impl` in the calling crate as well.
+//!
+//! The crate feature `trusted-len` implements [`core::iter::TrustedLen`] for the vector's
+//! iterators, which depends on the lang feature `trusted_len`.
+//!
+//! The crate is `no_std` unless the default feature `std` is enabled, which adds
+//! [`std::error::Error`] support. The `alloc` feature, which `std` implies, adds conversions to and
+//! from `Vec`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "const-callbacks", feature(const_trait_impl, const_cmp))]
+#![cfg_attr(feature = "trusted-len", feature(trusted_len))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-use core::ptr::addr_of;
-use core::{mem::ManuallyDrop, panic};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::panic;
+use core::ptr::{addr_of, addr_of_mut};
 
+#[cfg(any(
+    feature = "smallvec",
+    feature = "arrayvec",
+    feature = "tinyvec",
+    feature = "heapless",
+    feature = "alloc"
+))]
 use tap::Tap;
 
 #[cfg(feature = "fake-move")]
 mod addressing;
 mod assertions;
+mod bytes;
+mod cmp;
 mod iter;
-#[cfg(feature = "fake-move")]
 mod array_extension;
 mod macro_new;
+mod numeric;
+#[cfg(feature = "const-callbacks")]
+mod callbacks;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+
+#[cfg(feature = "bytemuck")]
+pub use bytemuck_impl::FromBytesError;
+pub use iter::{ConstVecIntoIter, ConstVecIter};
 
-pub struct CapacityError<T, const CAP: usize> {
+/// Returned when an operation would grow a vector past its capacity. `vector` is handed back as it
+/// was before the failed operation, and `rejected` holds whatever couldn't be added to it -- a
+/// single item by default, or the leftovers of a bulk operation.
+pub struct CapacityError<T, const CAP: usize, R = T> {
     pub vector: ConstVec<T, CAP>,
-    pub item: T,
+    pub rejected: R,
 }
 
-impl<T, const CAP: usize> core::fmt::Debug for CapacityError<T, CAP> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("CapacityError")
-            .field("capacity", &CAP)
-            .finish()
+/// The items rejected by an operation which overflowed a vector's capacity.
+pub trait Rejected<T> {
+    /// How many items were rejected.
+    fn rejected_len(&self) -> usize;
+}
+
+impl<T> Rejected<T> for T {
+    fn rejected_len(&self) -> usize {
+        1
     }
 }
 
-/// Essentially a [std::mem::MaybeUninit], but with externals exposed for const contexts
-union MaybeUninit<T> {
-    uninit: (),
-    value: ManuallyDrop<T>,
+impl<T, const CAP: usize> Rejected<T> for ConstVec<T, CAP> {
+    fn rejected_len(&self) -> usize {
+        self.len
+    }
 }
 
-impl<T> MaybeUninit<T> {
-    const fn uninit() -> Self {
-        Self { uninit: () }
+impl<T> Rejected<T> for &[T] {
+    fn rejected_len(&self) -> usize {
+        self.len()
     }
+}
 
-    /// #Safety
-    /// Undefined behavior where `T` is uninhabited
-    const unsafe fn assume_init(self) -> T {
-        ManuallyDrop::into_inner(self.value)
+#[cfg(feature = "alloc")]
+impl<T> Rejected<T> for alloc::vec::Vec<T> {
+    fn rejected_len(&self) -> usize {
+        self.len()
     }
 }
 
+impl<T, const CAP: usize, R: Rejected<T>> core::fmt::Debug for CapacityError<T, CAP, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CapacityError")
+            .field("capacity", &CAP)
+            .field("rejected", &self.rejected.rejected_len())
+            .finish()
+    }
+}
+
+impl<T, const CAP: usize, R> core::fmt::Display for CapacityError<T, CAP, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "capacity {CAP} exceeded")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, const CAP: usize, R: Rejected<T>> std::error::Error for CapacityError<T, CAP, R> {}
+
 #[repr(C)]
 pub struct ConstVec<T, const CAP: usize> {
     len: usize,
-    xs_addr: (),
     xs: [MaybeUninit<T>; CAP],
 }
 
 impl<T, const CAP: usize> ConstVec<T, CAP> {
     pub const fn new() -> Self {
         Self {
-            xs: unsafe { MaybeUninit::uninit().assume_init() },
-            xs_addr: (),
+            xs: [const { MaybeUninit::uninit() }; CAP],
             len: 0,
         }
     }
 
-    #[cfg(feature = "fake-move")]
     pub const fn from_array_exact(xs_exact: [T; CAP]) -> Self {
         Self::from_array(xs_exact)
     }
 
-    #[cfg(feature = "fake-move")]
     pub const fn from_array<const N: usize>(xs: [T; N]) -> Self {
         Self {
             len: N,
-            xs_addr: (),
             xs: array_extension::extend_array(xs),
         }
     }
 
+    /// Like [`ConstVec::from_array`], but with the elements in reverse order.
+    #[cfg(feature = "fake-move")]
+    pub const fn from_array_rev<const N: usize>(xs: [T; N]) -> Self {
+        assertions::Leq::<N, CAP>::assert();
+        // the elements are moved into the buffer, so they mustn't be dropped here as well
+        let xs = ManuallyDrop::new(xs);
+        let src = addr_of!(xs) as *const T;
+
+        let mut vector = Self::new();
+        let mut ix = N;
+        while ix > 0 {
+            ix -= 1;
+            vector = unsafe { vector.push_unchecked(src.add(ix).read()) };
+        }
+        vector
+    }
+
+    /// Creates a vector holding `n` copies of `value`. Panics if `n` is greater than `CAP`.
+    pub const fn filled(value: T, n: usize) -> Self
+    where
+        T: Copy,
+    {
+        if n > CAP {
+            panic!("fill length is greater than the capacity")
+        }
+
+        let mut vector = Self::new();
+        while vector.len < n {
+            vector = unsafe { vector.push_unchecked(value) };
+        }
+        vector
+    }
+
+    /// Concatenates `parts` with `sep` between each of them, like [`slice::join`]. Panics if the
+    /// result is longer than `CAP`, which fails to compile in a const.
+    pub const fn join_with(parts: &[&[T]], sep: T) -> Self
+    where
+        T: Copy,
+    {
+        let mut vector = Self::new();
+        let mut part_ix = 0;
+        while part_ix < parts.len() {
+            if part_ix > 0 {
+                if vector.len == CAP {
+                    panic!("the joined elements exceed the capacity")
+                }
+                vector = unsafe { vector.push_unchecked(sep) };
+            }
+
+            let part = parts[part_ix];
+            if vector.len + part.len() > CAP {
+                panic!("the joined elements exceed the capacity")
+            }
+            let mut ix = 0;
+            while ix < part.len() {
+                vector = unsafe { vector.push_unchecked(part[ix]) };
+                ix += 1;
+            }
+            part_ix += 1;
+        }
+        vector
+    }
+
+    /// Repeats the elements as many whole times as fit in `OUT`. Panics if they don't fit even
+    /// once. Named so that it doesn't shadow [`slice::repeat`] on the vector itself.
+    pub const fn repeated<const OUT: usize>(self) -> ConstVec<T, OUT>
+    where
+        T: Copy,
+    {
+        if self.len > OUT {
+            panic!("the elements don't fit in the new capacity")
+        }
+
+        let xs = self.as_slice();
+        let mut repeated = ConstVec::new();
+        if !xs.is_empty() {
+            while repeated.len + xs.len() <= OUT {
+                let mut ix = 0;
+                while ix < xs.len() {
+                    repeated = unsafe { repeated.push_unchecked(xs[ix]) };
+                    ix += 1;
+                }
+            }
+        }
+        core::mem::forget(self);
+        repeated
+    }
+
+    /// Moves the elements of `v` into a new vector. If `v` is longer than `CAP`, the error holds a
+    /// vector of the first `CAP` elements and rejects the rest of `v`.
+    #[cfg(feature = "alloc")]
+    pub fn try_from_vec(
+        mut v: alloc::vec::Vec<T>,
+    ) -> Result<Self, CapacityError<T, CAP, alloc::vec::Vec<T>>> {
+        let rejected = v.split_off(v.len().min(CAP));
+        let vector = v.into_iter().collect();
+
+        if rejected.is_empty() {
+            Ok(vector)
+        } else {
+            Err(CapacityError { vector, rejected })
+        }
+    }
+
+    /// Clones the elements of `src` into a new vector. If `src` is longer than `CAP`, the error
+    /// holds a vector of the first `CAP` elements and rejects the rest of `src`.
+    pub fn try_from_slice(src: &[T]) -> Result<Self, CapacityError<T, CAP, &[T]>>
+    where
+        T: Clone,
+    {
+        let (fits, rejected) = src.split_at(src.len().min(CAP));
+        let mut vector = Self::new();
+        for item in fits {
+            vector = unsafe { vector.push_unchecked(item.clone()) };
+        }
+
+        if rejected.is_empty() {
+            Ok(vector)
+        } else {
+            Err(CapacityError { vector, rejected })
+        }
+    }
+
+    /// The inverse of [`ConstVec::from_array_exact`]. Only succeeds if the vector is full,
+    /// otherwise it's handed back unchanged.
     #[cfg(feature = "fake-move")]
+    pub const fn try_into_array(self) -> Result<[T; CAP], Self> {
+        if self.len == CAP {
+            // every slot is initialized, so the whole buffer can be moved out at once
+            let array = unsafe { addressing::extract_addr!(self<[T; CAP]>).read() };
+            core::mem::forget(self);
+            Ok(array)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Moves the elements into a vector with a larger capacity. Fails to compile if `NEW_CAP` is
+    /// smaller than `CAP`.
     pub const fn upgrade<const NEW_CAP: usize>(self) -> ConstVec<T, NEW_CAP> {
+        let (len, xs) = self.into_raw_parts();
         ConstVec {
-            len: self.len,
-            xs_addr: (),
-            xs: array_extension::extend_uninit_array(self.xs),
+            len,
+            xs: array_extension::extend_uninit_array(xs),
         }
     }
 
+    /// Fails to compile if `N` elements wouldn't fit in the vector, with the same error as
+    /// [`ConstVec::from_array`]. Useful for checking the sizes of derived tables up front.
+    pub const fn assert_fits<const N: usize>() {
+        assertions::Leq::<N, CAP>::assert();
+    }
+
+    /// Takes the length and buffer out of the vector without running its destructor, since fields
+    /// can't be moved out of a type which implements [`Drop`].
+    const fn into_raw_parts(self) -> (usize, [MaybeUninit<T>; CAP]) {
+        let this = ManuallyDrop::new(self);
+        let this = addr_of!(this) as *const Self;
+        unsafe { (addr_of!((*this).len).read(), addr_of!((*this).xs).read()) }
+    }
+
     pub const fn len(&self) -> usize {
         self.len
     }
@@ -94,55 +310,370 @@ impl<T, const CAP: usize> ConstVec<T, CAP> {
         self.len == 0
     }
 
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    pub const fn remaining_capacity(&self) -> usize {
+        CAP - self.len
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    /// A pointer to the start of the buffer, valid for `len` reads. The rest of the buffer is
+    /// uninitialized.
+    pub const fn as_ptr(&self) -> *const T {
+        addr_of!(self.xs) as *const T
+    }
+
+    /// A pointer to the start of the buffer, valid for `len` reads and `CAP` writes. Writes past
+    /// `len` only become visible once [`ConstVec::set_len`] is called.
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        addr_of_mut!(self.xs) as *mut T
+    }
+
     pub const fn as_slice(&self) -> &[T] {
-        unsafe { core::slice::from_raw_parts(addr_of!(self.xs_addr) as *const T, self.len) }
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+
+    /// The uninitialized tail of the buffer, `len..CAP`. Elements written here can be added to the
+    /// vector with [`ConstVec::set_len`].
+    pub const fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            let spare = self.as_mut_ptr().add(self.len) as *mut MaybeUninit<T>;
+            core::slice::from_raw_parts_mut(spare, CAP - self.len)
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
     }
 
     pub const fn get(&self, ix: usize) -> Option<&T> {
         if ix < self.len {
-            Some(unsafe { core::mem::transmute(&self.xs[ix].value) })
+            Some(unsafe { self.xs[ix].assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// The elements at `a` and `b`, or `None` if either is out of bounds.
+    pub const fn get_pair(&self, a: usize, b: usize) -> Option<(&T, &T)> {
+        match (self.get(a), self.get(b)) {
+            (Some(a), Some(b)) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    pub const fn get_mut(&mut self, ix: usize) -> Option<&mut T> {
+        if ix < self.len {
+            Some(unsafe { self.xs[ix].assume_init_mut() })
         } else {
             None
         }
     }
 
+    pub const fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub const fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    pub const fn last(&self) -> Option<&T> {
+        if self.len > 0 {
+            self.get(self.len - 1)
+        } else {
+            None
+        }
+    }
+
+    pub const fn last_mut(&mut self) -> Option<&mut T> {
+        if self.len > 0 {
+            self.get_mut(self.len - 1)
+        } else {
+            None
+        }
+    }
+
+    /// [`ConstVec::try_swap_remove`] for `Copy` elements, which can be read out with plain copies
+    /// and so don't need the `fake-move` feature.
+    pub const fn swap_remove_copy(mut self, ix: usize) -> (Self, Option<T>)
+    where
+        T: Copy,
+    {
+        if ix < self.len {
+            let xs = self.as_slice();
+            let removing = xs[ix];
+            let last = xs[xs.len() - 1];
+            self.xs[ix] = MaybeUninit::new(last);
+            let len = self.len - 1;
+            self = unsafe { self.set_len(len) };
+            (self, Some(removing))
+        } else {
+            (self, None)
+        }
+    }
+
     #[cfg(feature = "fake-move")]
     pub const fn try_swap_remove(mut self, ix: usize) -> (Self, Option<T>) {
-        if self.len > 0 {
-            if ix == self.len - 1 {
-                self.try_pop()
-            } else {
-                unsafe {
-                    let removing = addressing::copy_item!(self<T>[ix]);
-                    let swapping = addressing::copy_item!(self<ManuallyDrop<T>>[self.len - 1]);
-                    self.xs[ix] = MaybeUninit { value: swapping };
-                    let len = self.len - 1;
-                    self = self.set_len(len);
-                    (self, Some(removing))
-                }
+        if ix >= self.len {
+            return (self, None);
+        }
+
+        if ix == self.len - 1 {
+            self.pop()
+        } else {
+            unsafe {
+                let removing = addressing::copy_item!(self<T>[ix]);
+                let swapping = addressing::copy_item!(self<MaybeUninit<T>>[self.len - 1]);
+                self.xs[ix] = swapping;
+                let len = self.len - 1;
+                self = self.set_len(len);
+                (self, Some(removing))
+            }
+        }
+    }
+
+    #[cfg(feature = "fake-move")]
+    pub const fn try_remove(mut self, ix: usize) -> (Self, Option<T>) {
+        if ix < self.len {
+            unsafe {
+                let removing = addressing::copy_item!(self<T>[ix]);
+                self = self.close_gap(ix);
+                (self, Some(removing))
             }
         } else {
             (self, None)
         }
     }
 
+    /// Shifts everything after `ix` down by one, overwriting the element at `ix` without dropping
+    /// it.
+    ///
+    /// # Safety
+    ///
+    /// `ix` must be less than the length, and the element at `ix` must already have been moved out.
+    #[cfg(feature = "fake-move")]
+    const unsafe fn close_gap(mut self, ix: usize) -> Self {
+        let mut shifting = ix + 1;
+        while shifting < self.len {
+            let item = addressing::copy_item!(self<MaybeUninit<T>>[shifting]);
+            self.xs[shifting - 1] = item;
+            shifting += 1;
+        }
+        let len = self.len - 1;
+        self.set_len(len)
+    }
+
+    /// Takes the first element off the front of the vector, handing it back along with the rest.
+    /// Returns `None` if the vector is empty.
     #[cfg(feature = "fake-move")]
-    pub const fn pop(mut self) -> (Self, T) {
+    pub const fn split_first(self) -> Option<(T, Self)> {
+        if self.len > 0 {
+            unsafe {
+                let first = addressing::copy_item!(self<T>[0]);
+                Some((first, self.close_gap(0)))
+            }
+        } else {
+            core::mem::forget(self);
+            None
+        }
+    }
+
+    /// Takes the last element off the end of the vector, handing it back along with the rest.
+    /// Returns `None` if the vector is empty.
+    #[cfg(feature = "fake-move")]
+    pub const fn split_last(self) -> Option<(T, Self)> {
         if self.len > 0 {
             let new_len = self.len - 1;
-            let item = unsafe {
-                let item = addressing::copy_item!(self<T>[new_len]);
-                self = self.set_len(new_len);
-                item
-            };
-            (self, item)
+            unsafe {
+                let last = addressing::copy_item!(self<T>[new_len]);
+                Some((last, self.set_len(new_len)))
+            }
         } else {
-            panic!()
+            core::mem::forget(self);
+            None
+        }
+    }
+
+    #[cfg(feature = "fake-move")]
+    pub const fn try_insert(mut self, ix: usize, item: T) -> Result<Self, CapacityError<T, CAP>> {
+        if ix > self.len {
+            panic!("insertion index is past the end of the vector")
+        }
+
+        if self.len < CAP {
+            unsafe {
+                // shift everything from the insertion point up by one, starting at the back
+                let mut shifting = self.len;
+                while shifting > ix {
+                    let item = addressing::copy_item!(self<MaybeUninit<T>>[shifting - 1]);
+                    self.xs[shifting] = item;
+                    shifting -= 1;
+                }
+                self.xs[ix] = MaybeUninit::new(item);
+                let len = self.len + 1;
+                self = self.set_len(len);
+                Ok(self)
+            }
+        } else {
+            Err(CapacityError {
+                vector: self,
+                rejected: item,
+            })
         }
     }
 
     #[cfg(feature = "fake-move")]
-    pub const fn try_pop(mut self) -> (Self, Option<T>) {
+    pub const fn try_swap(mut self, a: usize, b: usize) -> Self {
+        if a >= self.len || b >= self.len {
+            panic!("swap index is out of bounds")
+        }
+
+        unsafe {
+            let item_a = addressing::copy_item!(self<MaybeUninit<T>>[a]);
+            let item_b = addressing::copy_item!(self<MaybeUninit<T>>[b]);
+            self.xs[a] = item_b;
+            self.xs[b] = item_a;
+        }
+        self
+    }
+
+    #[cfg(feature = "fake-move")]
+    pub const fn reverse(mut self) -> Self {
+        let mut front = 0;
+        while front < self.len / 2 {
+            let back = self.len - 1 - front;
+            self = self.try_swap(front, back);
+            front += 1;
+        }
+        self
+    }
+
+    /// Moves every element of `other` onto the end of this vector. If they don't all fit, `other`
+    /// is rejected as a whole.
+    #[cfg(feature = "fake-move")]
+    pub const fn try_append<const B: usize>(
+        mut self,
+        other: ConstVec<T, B>,
+    ) -> Result<Self, CapacityError<T, CAP, ConstVec<T, B>>> {
+        if self.len + other.len > CAP {
+            return Err(CapacityError {
+                vector: self,
+                rejected: other,
+            });
+        }
+
+        let mut ix = 0;
+        while ix < other.len {
+            unsafe {
+                let item = addressing::copy_item!(other<T>[ix]);
+                self = self.push_unchecked(item);
+            }
+            ix += 1;
+        }
+        // every element has been moved out of `other`
+        core::mem::forget(other);
+        Ok(self)
+    }
+
+    /// Splits the vector in two, the first holding the elements before `mid` and the second the
+    /// rest. Panics if `mid` is greater than the length.
+    #[cfg(feature = "fake-move")]
+    pub const fn split_at(mut self, mid: usize) -> (Self, Self) {
+        if mid > self.len {
+            panic!("split index is past the end of the vector")
+        }
+
+        let mut tail = Self::new();
+        let mut ix = mid;
+        while ix < self.len {
+            unsafe {
+                let item = addressing::copy_item!(self<T>[ix]);
+                tail = tail.push_unchecked(item);
+            }
+            ix += 1;
+        }
+        self = unsafe { self.set_len(mid) };
+        (self, tail)
+    }
+
+    /// Splits the vector into chunks of `CHUNK` elements, the last of which may be shorter. Panics
+    /// if `CHUNK` is zero or if the chunks don't fit in `OUT`.
+    #[cfg(feature = "fake-move")]
+    pub const fn chunked<const CHUNK: usize, const OUT: usize>(
+        mut self,
+    ) -> ConstVec<ConstVec<T, CHUNK>, OUT> {
+        if CHUNK == 0 {
+            panic!("chunk size must be non-zero")
+        }
+        if self.len.div_ceil(CHUNK) > OUT {
+            panic!("the chunks exceed the capacity")
+        }
+
+        let len = self.len;
+        self = unsafe { self.set_len(0) };
+
+        let mut chunks = ConstVec::new();
+        let mut ix = 0;
+        while ix < len {
+            let mut chunk = ConstVec::new();
+            while chunk.len < CHUNK && ix < len {
+                unsafe {
+                    let item = addressing::copy_item!(self<T>[ix]);
+                    chunk = chunk.push_unchecked(item);
+                }
+                ix += 1;
+            }
+            chunks = unsafe { chunks.push_unchecked(chunk) };
+        }
+        core::mem::forget(self);
+        chunks
+    }
+
+    /// Pairs up the elements of both vectors, stopping at the end of the shorter one. The leftover
+    /// elements of the longer vector are leaked rather than dropped, since that can't happen at
+    /// const-time.
+    #[cfg(feature = "fake-move")]
+    pub const fn zip<U, const B: usize>(
+        mut self,
+        mut other: ConstVec<U, B>,
+    ) -> ConstVec<(T, U), CAP> {
+        let len = if self.len < other.len {
+            self.len
+        } else {
+            other.len
+        };
+        self = unsafe { self.set_len(0) };
+        other = unsafe { other.set_len(0) };
+
+        let mut zipped = ConstVec::new();
+        let mut ix = 0;
+        while ix < len {
+            unsafe {
+                let a = addressing::copy_item!(self<T>[ix]);
+                let b = addressing::copy_item!(other<U>[ix]);
+                zipped = zipped.push_unchecked((a, b));
+            }
+            ix += 1;
+        }
+        core::mem::forget(self);
+        core::mem::forget(other);
+        zipped
+    }
+
+    /// Removes the last element, or returns `None` if the vector is empty. This matches `Vec::pop`,
+    /// except that the vector is handed back as well.
+    #[cfg(feature = "fake-move")]
+    pub const fn pop(mut self) -> (Self, Option<T>) {
         if self.len > 0 {
             let new_len = self.len - 1;
             let item = unsafe {
@@ -156,17 +687,27 @@ impl<T, const CAP: usize> ConstVec<T, CAP> {
         }
     }
 
+    /// Removes the last element, for callers which know the vector isn't empty. Panics if it is.
+    #[cfg(feature = "fake-move")]
+    pub const fn pop_or_panic(self) -> (Self, T) {
+        if self.len > 0 {
+            unsafe { self.pop_unchecked() }
+        } else {
+            panic!("pop from an empty vector")
+        }
+    }
+
     #[cfg(feature = "fake-move")]
     /// # Safety
     ///
+    /// The vector must not be empty.
+    ///
     /// At the time of writing, there are a lot of limitations around const. In this case, the
     /// relevant issue is that we are not allowed to get references to objects which may contain
-    /// [`UnsafeCell`]s -- both `&self` and `&self.xs` are impossible. There is a lot of weird code
-    /// in here to get around this, but the bottom line is that I don't know if this is actually a
-    /// sound thing to do, and actually, with pointer provenance I think it is completey unsound.
-    /// However, if you still feel like using this approach, I guess just avoid putting in types
-    /// which have [`UnsafeCell`]s in them (which shouldn't be hard anyway, given that const
-    /// disallows heap allocations).
+    /// [`UnsafeCell`](core::cell::UnsafeCell)s -- both `&self` and `&self.xs` are impossible.
+    /// Instead the last element is read through a raw pointer to `xs`, which never creates a
+    /// reference. If `T` contains an [`UnsafeCell`](core::cell::UnsafeCell) this approach may
+    /// still not be allowed.
     pub const unsafe fn pop_unchecked(mut self) -> (Self, T) {
         debug_assert!(self.len > 0);
         let new_len = self.len - 1;
@@ -183,30 +724,412 @@ impl<T, const CAP: usize> ConstVec<T, CAP> {
         }
     }
 
+    /// `&mut` counterpart to [`ConstVec::push`], for runtime code which only holds a reference.
+    pub(crate) fn push_mut(&mut self, item: T) {
+        assert!(self.len < CAP, "capacity {CAP} exceeded");
+        self.xs[self.len] = MaybeUninit::new(item);
+        self.len += 1;
+    }
+
     pub const fn try_push(self, item: T) -> Result<Self, CapacityError<T, CAP>> {
         if self.len < CAP {
             unsafe { Ok(self.push_unchecked(item)) }
         } else {
-            Err(CapacityError { vector: self, item })
+            Err(CapacityError {
+                vector: self,
+                rejected: item,
+            })
+        }
+    }
+
+    /// Moves every element of `xs` onto the end of the vector. Panics if they don't all fit, which
+    /// fails to compile in a const.
+    pub const fn extend_from_array<const N: usize>(mut self, xs: [T; N]) -> Self {
+        if self.len + N > CAP {
+            panic!("the array doesn't fit in the remaining capacity")
+        }
+
+        // the elements are moved into the buffer, so they mustn't be dropped here as well
+        let xs = ManuallyDrop::new(xs);
+        unsafe {
+            let end = self.as_mut_ptr().add(self.len);
+            core::ptr::copy_nonoverlapping(addr_of!(xs) as *const T, end, N);
+            let len = self.len + N;
+            self.set_len(len)
+        }
+    }
+
+    /// Copies every element of `src` onto the end of the vector. If they don't all fit, `src` is
+    /// rejected as a whole.
+    pub const fn try_extend_from_slice(
+        mut self,
+        src: &[T],
+    ) -> Result<Self, CapacityError<T, CAP, &[T]>>
+    where
+        T: Copy,
+    {
+        if self.len + src.len() > CAP {
+            return Err(CapacityError {
+                vector: self,
+                rejected: src,
+            });
+        }
+
+        let mut ix = 0;
+        while ix < src.len() {
+            self = unsafe { self.push_unchecked(src[ix]) };
+            ix += 1;
         }
+        Ok(self)
     }
 
+    /// # Safety
+    ///
+    /// The vector must not be full, i.e. `self.len() < CAP`.
     pub const unsafe fn push_unchecked(mut self, item: T) -> Self {
         debug_assert!(self.len < CAP);
-        self.xs[self.len] = MaybeUninit {
-            // TODO actually make this unchecked
-            value: ManuallyDrop::new(item),
-        };
+        self.xs[self.len] = MaybeUninit::new(item);
         let len = self.len;
         self = self.set_len(len + 1);
         self
     }
 
+    /// Shortens the vector to `new_len` elements, dropping the rest. Does nothing if the vector is
+    /// already no longer than `new_len`.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            let tail = addr_of_mut!(self.as_mut_slice()[new_len..]);
+            // lower the length first so a panicking destructor can't cause a double drop
+            self.len = new_len;
+            unsafe { core::ptr::drop_in_place(tail) };
+        }
+    }
+
+    /// Grows the vector to `new_len` with clones of `value`, or truncates it if it's already
+    /// longer. Panics if `new_len` is greater than `CAP`.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len > self.len {
+            assert!(new_len <= CAP, "capacity {CAP} exceeded");
+            while self.len + 1 < new_len {
+                self.push_mut(value.clone());
+            }
+            self.push_mut(value);
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    /// Grows the vector to `new_len` with the results of calling `f`, or truncates it if it's
+    /// already longer. Panics if `new_len` is greater than `CAP`.
+    pub fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        assert!(new_len <= CAP, "capacity {CAP} exceeded");
+        while self.len < new_len {
+            self.push_mut(f());
+        }
+        self.truncate(new_len);
+    }
+
+    /// Runtime counterpart to `map`, which takes any `FnMut` closure rather than a `[const] Fn`.
+    pub fn map_runtime<U, F>(self, f: F) -> ConstVec<U, CAP>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.into_iter().map(f).collect()
+    }
+
+    /// Replaces `range` with the items of `replace_with`, handing back the removed elements. Unlike
+    /// `Vec::splice`, the replacement happens straight away rather than when the returned iterator
+    /// is dropped. Panics if the range is out of bounds, or if the result doesn't fit in `CAP`.
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> ConstVecIntoIter<T, CAP>
+    where
+        R: core::ops::RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        use core::ops::Bound;
+
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).unwrap_or_else(|| {
+                panic!(
+                    "splice range end {end} is out of range for length {}",
+                    self.len
+                )
+            }),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+        assert!(
+            start <= end,
+            "splice range starts at {start} but ends at {end}"
+        );
+        assert!(
+            end <= self.len,
+            "splice range end {end} is out of range for length {}",
+            self.len
+        );
+
+        // rebuild the vector in place, so a panic partway through drops everything exactly once
+        let mut removed = Self::new();
+        let mut tail = Self::new();
+        for (ix, item) in core::mem::take(self).into_iter().enumerate() {
+            if ix < start {
+                self.push_mut(item);
+            } else if ix < end {
+                removed.push_mut(item);
+            } else {
+                tail.push_mut(item);
+            }
+        }
+        self.extend(replace_with);
+        self.extend(tail);
+        removed.into_iter()
+    }
+
+    /// Moves every element of `other` onto the end of this vector, leaving `other` empty. Panics if
+    /// they don't all fit, in which case neither vector is changed.
+    pub fn append<const B: usize>(&mut self, other: &mut ConstVec<T, B>) {
+        assert!(self.len + other.len <= CAP, "capacity {CAP} exceeded");
+        self.extend(core::mem::take(other));
+    }
+
+    /// Removes consecutive repeated elements, keeping the first of each run.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Removes consecutive elements which map to the same key, keeping the first of each run.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// Removes consecutive elements for which `same_bucket` returns true. As with
+    /// [`Vec::dedup_by`], it's passed the element being considered first and the last element kept
+    /// second.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        // rebuild the vector in place, so a panic in `same_bucket` drops everything exactly once
+        let old = core::mem::take(self);
+        for mut item in old {
+            let duplicate = match self.last_mut() {
+                Some(kept) => same_bucket(&mut item, kept),
+                None => false,
+            };
+            if !duplicate {
+                self.push_mut(item);
+            }
+        }
+    }
+
+    /// Keeps only the elements for which `f` returns true, letting `f` modify each element as it's
+    /// visited, like `Vec::retain_mut`. The removed elements are dropped.
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        // rebuild the vector in place, so a panic in `f` drops everything exactly once
+        let old = core::mem::take(self);
+        for mut item in old {
+            if f(&mut item) {
+                self.push_mut(item);
+            }
+        }
+    }
+
+    /// Const counterpart to [`ConstVec::truncate`]. Destructors can't run at const-time, so this is
+    /// only available for `Copy` elements, which have none.
+    pub const fn truncate_const(self, new_len: usize) -> Self
+    where
+        T: Copy,
+    {
+        if new_len < self.len {
+            unsafe { self.set_len(new_len) }
+        } else {
+            self
+        }
+    }
+
+    /// Drops every element, leaving the vector empty.
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    /// Const counterpart to [`ConstVec::clear`], only available for `Copy` elements.
+    pub const fn clear_const(self) -> Self
+    where
+        T: Copy,
+    {
+        self.truncate_const(0)
+    }
+
+    /// Like `Vec::set_len`, lowering the length doesn't drop anything. Use [`ConstVec::truncate`]
+    /// to drop the removed elements.
+    ///
+    /// # Safety
+    ///
+    /// `length` must not exceed `CAP`, and the first `length` elements must be initialized.
     pub const unsafe fn set_len(mut self, length: usize) -> Self {
         debug_assert!(length <= CAP);
         self.len = length;
         self
     }
+
+    /// Safe counterpart to [`ConstVec::set_len`], which can only shrink the vector. The vector is
+    /// handed back unchanged as the error if `length` is greater than the current length. Like
+    /// `set_len`, the removed elements are leaked rather than dropped.
+    pub const fn try_set_len(self, length: usize) -> Result<Self, Self> {
+        if length <= self.len {
+            Ok(unsafe { self.set_len(length) })
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<A, B, const CAP: usize> ConstVec<(A, B), CAP> {
+    /// The inverse of [`ConstVec::zip`], splitting each pair into two parallel vectors.
+    #[cfg(feature = "fake-move")]
+    pub const fn unzip(mut self) -> (ConstVec<A, CAP>, ConstVec<B, CAP>) {
+        let len = self.len;
+        self = unsafe { self.set_len(0) };
+
+        let mut left = ConstVec::new();
+        let mut right = ConstVec::new();
+        let mut ix = 0;
+        while ix < len {
+            unsafe {
+                // read the halves separately, since moving out of a tuple needs it to be dropped
+                let pair = addressing::extract_addr!(self<(A, B)>).add(ix);
+                left = left.push_unchecked(addr_of!((*pair).0).read());
+                right = right.push_unchecked(addr_of!((*pair).1).read());
+            }
+            ix += 1;
+        }
+        core::mem::forget(self);
+        (left, right)
+    }
+}
+
+impl<T, const CAP: usize> Drop for ConstVec<T, CAP> {
+    fn drop(&mut self) {
+        // only the first `len` slots are initialized, the rest must not be touched
+        for ix in 0..self.len {
+            unsafe { self.xs[ix].assume_init_drop() };
+        }
+    }
+}
+
+impl<T, const CAP: usize> Default for ConstVec<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const CAP: usize> core::ops::Deref for ConstVec<T, CAP> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const CAP: usize> core::ops::DerefMut for ConstVec<T, CAP> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, I, const CAP: usize> core::ops::Index<I> for ConstVec<T, CAP>
+where
+    I: core::slice::SliceIndex<[T]>,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &I::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T, I, const CAP: usize> core::ops::IndexMut<I> for ConstVec<T, CAP>
+where
+    I: core::slice::SliceIndex<[T]>,
+{
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+impl<T, const CAP: usize> AsRef<[T]> for ConstVec<T, CAP> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const CAP: usize> AsMut<[T]> for ConstVec<T, CAP> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const CAP: usize> core::borrow::Borrow<[T]> for ConstVec<T, CAP> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const CAP: usize> core::borrow::BorrowMut<[T]> for ConstVec<T, CAP> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Clone, const CAP: usize> Clone for ConstVec<T, CAP> {
+    fn clone(&self) -> Self {
+        // if cloning an element panics, the elements cloned so far are dropped along with `clone`
+        let mut clone = Self::new();
+        for item in self {
+            clone = unsafe { clone.push_unchecked(item.clone()) };
+        }
+        clone
+    }
+}
+
+/// Fails to compile if `N` is greater than `CAP`, like [`ConstVec::from_array`].
+///
+/// ```compile_fail,E0080
+/// let _: const_push::ConstVec<u32, 2> = [10, 20, 30].into();
+/// ```
+impl<T, const N: usize, const CAP: usize> From<[T; N]> for ConstVec<T, CAP> {
+    fn from(value: [T; N]) -> Self {
+        Self::from_array(value)
+    }
+}
+
+/// See [`ConstVec::try_from_slice`].
+impl<'a, T: Clone, const CAP: usize> TryFrom<&'a [T]> for ConstVec<T, CAP> {
+    type Error = CapacityError<T, CAP, &'a [T]>;
+
+    fn try_from(value: &'a [T]) -> Result<Self, Self::Error> {
+        Self::try_from_slice(value)
+    }
 }
 
 #[cfg(feature = "smallvec")]
@@ -221,3 +1144,41 @@ impl<T, const N: usize> From<ConstVec<T, N>> for arrayvec::ArrayVec<T, N> {
         arrayvec::ArrayVec::new().tap_mut(|v| v.extend(value))
     }
 }
+
+#[cfg(feature = "tinyvec")]
+impl<T: Default, const N: usize> From<ConstVec<T, N>> for tinyvec::ArrayVec<[T; N]> {
+    fn from(value: ConstVec<T, N>) -> Self {
+        tinyvec::ArrayVec::new().tap_mut(|v| v.extend(value))
+    }
+}
+
+/// `heapless::Vec` has the same capacity, so this never fails. The matching `TryFrom` comes from
+/// this impl, with an [`Infallible`](core::convert::Infallible) error.
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> From<ConstVec<T, N>> for heapless::Vec<T, N> {
+    fn from(value: ConstVec<T, N>) -> Self {
+        heapless::Vec::new().tap_mut(|v| v.extend(value))
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> From<heapless::Vec<T, N>> for ConstVec<T, N> {
+    fn from(value: heapless::Vec<T, N>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> From<ConstVec<T, N>> for alloc::vec::Vec<T> {
+    fn from(value: ConstVec<T, N>) -> Self {
+        alloc::vec::Vec::with_capacity(value.len()).tap_mut(|v| v.extend(value))
+    }
+}
+
+/// Formats the live elements as a list, like the [`Debug`](core::fmt::Debug) impl.
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format, const CAP: usize> defmt::Format for ConstVec<T, CAP> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{}", self.as_slice())
+    }
+}
@@ -1,22 +1,28 @@
-use cfg_if::cfg_if;
-
-cfg_if! {
-    if #[cfg(feature = "fake-move")] {
-        #[macro_export]
-        macro_rules! constvec {
-            () => { ::const_push::ConstVec<_, _>::new() };
-            ($elem:expr; $n:literal..$cap:literal) => {
-                ::const_push::constvec_by_array!($elem;$n..$cap)
-            };
-            ($($x:expr),+ $(,)? ; ..$cap:literal) => {
-                ::const_push::constvec_by_array!($($x,)*; ..$cap)
-            };
-        }
-    } else {
-
-    }
+/// Creates a vector. `constvec![]` and `constvec![; CAP]` are empty, `constvec![a, b, c]` is full,
+/// and the other forms are as in [`constvec_by_array!`](crate::constvec_by_array).
+#[macro_export]
+macro_rules! constvec {
+    () => {
+        ::const_push::ConstVec::new()
+    };
+    (; $cap:literal) => {
+        ::const_push::ConstVec::<_, $cap>::new()
+    };
+    ($elem:expr; $n:literal..$cap:literal) => {
+        ::const_push::constvec_by_array!($elem;$n..$cap)
+    };
+    ($($x:expr),+ $(,)? ; ..$cap:literal) => {
+        ::const_push::constvec_by_array!($($x,)*; ..$cap)
+    };
+    ($($x:expr),+ $(,)?) => {
+        ::const_push::ConstVec::from_array_exact([$($x,)+])
+    };
 }
 
+/// Creates a vector of capacity `CAP` through an array. `constvec_by_array![x; N..CAP]` holds `N`
+/// copies of `x` -- read `N..CAP` as "`N` elements, room for `CAP`", not as a range -- and
+/// `constvec_by_array![a, b, c; ..CAP]` holds the listed elements. Either fails to compile in a
+/// const if the elements don't fit.
 #[macro_export]
 macro_rules! constvec_by_array {
     ($elem:expr; $n:literal..$cap:literal) => {
@@ -26,3 +32,12 @@ macro_rules! constvec_by_array {
         ::const_push::ConstVec::<_,$cap>::from_array([$($x,)+])
      };
 }
+
+/// Concatenates arrays into a single vector of capacity `CAP`, as in
+/// `constvec_concat!(A, B, [5, 6]; ..10)`. Fails to compile in a const if they don't all fit.
+#[macro_export]
+macro_rules! constvec_concat {
+    ($($xs:expr),+ $(,)? ; ..$cap:literal) => {
+        ::const_push::ConstVec::<_, $cap>::new()$(.extend_from_array($xs))+
+    };
+}
@@ -0,0 +1,22 @@
+use crate::ConstVec;
+
+// arithmetic traits can't be called at const-time on stable, so each integer type gets its own impl
+macro_rules! impl_sum {
+    ($($int:ty),*) => {$(
+        impl<const CAP: usize> ConstVec<$int, CAP> {
+            /// Adds up the elements at const-time. Overflow panics, or fails to compile in a const.
+            pub const fn sum(&self) -> $int {
+                let xs = self.as_slice();
+                let mut sum = 0;
+                let mut ix = 0;
+                while ix < xs.len() {
+                    sum += xs[ix];
+                    ix += 1;
+                }
+                sum
+            }
+        }
+    )*};
+}
+
+impl_sum!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
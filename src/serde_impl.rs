@@ -0,0 +1,38 @@
+use core::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::ConstVec;
+
+impl<T: Serialize, const CAP: usize> Serialize for ConstVec<T, CAP> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const CAP: usize> Deserialize<'de> for ConstVec<T, CAP> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ConstVecVisitor(PhantomData))
+    }
+}
+
+struct ConstVecVisitor<T, const CAP: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const CAP: usize> Visitor<'de> for ConstVecVisitor<T, CAP> {
+    type Value = ConstVec<T, CAP>;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a sequence of at most {CAP} elements")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut vector = ConstVec::new();
+        while let Some(item) = seq.next_element()? {
+            vector = vector
+                .try_push(item)
+                .map_err(|_| A::Error::invalid_length(CAP + 1, &self))?;
+        }
+        Ok(vector)
+    }
+}
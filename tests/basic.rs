@@ -1,3 +1,5 @@
+#![feature(const_precise_live_drops)]
+
 use std::{assert_eq, matches};
 
 use const_push::ConstVec;
@@ -12,7 +14,7 @@ fn basic_push_test() {
 }
 
 const fn drop_const_vec() {
-    ConstVec::<u32, 10>::new();
+    core::mem::forget(ConstVec::<u32, 10>::new());
 }
 #[allow(unused)]
 const DROP_CONST_VEC: () = drop_const_vec();
@@ -22,6 +24,7 @@ const fn drop_const_vec_with_elems() {
     assert!(matches!(c.get(0), Some(&x) if x == 10));
     assert!(matches!(c.get(1), Some(&x) if x == 20));
     assert!(matches!(c.get(2), Some(&x) if x == 30));
+    core::mem::forget(c);
 }
 #[allow(unused)]
 const DROP_CONST_VEC_WITH_ELEMS: () = drop_const_vec_with_elems();
@@ -29,7 +32,9 @@ const DROP_CONST_VEC_WITH_ELEMS: () = drop_const_vec_with_elems();
 const fn pop_elems() -> u32 {
     let c = ConstVec::<u32, 10>::new().push(10).push(20);
 
-    c.pop().1
+    let (c, popped) = c.pop_or_panic();
+    core::mem::forget(c);
+    popped
 }
 const POPPED_ELEM: u32 = pop_elems();
 #[test]
@@ -37,6 +42,22 @@ fn test_popped_elem() {
     assert_eq!(POPPED_ELEM, 20)
 }
 
+const POP: (ConstVec<u32, 4>, Option<u32>) = ConstVec::from_array([10, 20]).pop();
+const POP_EMPTY: (ConstVec<u32, 4>, Option<u32>) = ConstVec::new().pop();
+#[test]
+fn test_pop() {
+    assert_eq!(POP.0, [10]);
+    assert_eq!(POP.1, Some(20));
+    assert!(POP_EMPTY.0.is_empty());
+    assert_eq!(POP_EMPTY.1, None);
+}
+
+#[test]
+#[should_panic(expected = "pop from an empty vector")]
+fn test_pop_or_panic_empty() {
+    let _ = ConstVec::<u32, 4>::new().pop_or_panic();
+}
+
 const fn try_swap_remove() -> (ConstVec<u32, 10>, Option<u32>) {
     ConstVec::new()
         .push(10)
@@ -48,24 +69,546 @@ const fn try_swap_remove() -> (ConstVec<u32, 10>, Option<u32>) {
 const TRY_SWAP_REMOVE_TEST: (ConstVec<u32, 10>, Option<u32>) = try_swap_remove();
 #[test]
 fn test_try_swap_remove() {
+    assert_eq!(TRY_SWAP_REMOVE_TEST.0, [10, 40, 30]);
+    assert_eq!(TRY_SWAP_REMOVE_TEST.1, Some(20))
+}
+
+const TRY_SWAP_REMOVE_OUT_OF_BOUNDS: (ConstVec<u32, 10>, Option<u32>) =
+    ConstVec::from_array([10, 20]).try_swap_remove(3);
+#[test]
+fn test_try_swap_remove_out_of_bounds() {
+    assert_eq!(TRY_SWAP_REMOVE_OUT_OF_BOUNDS.0, [10, 20]);
+    assert_eq!(TRY_SWAP_REMOVE_OUT_OF_BOUNDS.1, None);
+
+    // the slots past the length are uninitialized, so nothing may be read from them
+    let strings = ConstVec::<String, 4>::from_array(["a", "b"].map(String::from));
+    let (strings, removed) = strings.try_swap_remove(3);
+    assert_eq!(removed, None);
+    assert_eq!(strings.as_slice(), ["a", "b"]);
+}
+
+#[test]
+fn test_mutate_in_place() {
+    let mut c = ConstVec::<String, 4>::new()
+        .push("a".to_string())
+        .push("b".to_string());
+    c.get_mut(1).unwrap().push('c');
+    c.as_mut_slice()[0].push('d');
+    assert!(c.get_mut(2).is_none());
+
+    assert_eq!(c.get(1).map(String::as_str), Some("bc"));
+    assert_eq!(c.as_slice(), ["ad", "bc"]);
+}
+
+type SwapRemoveResult = (ConstVec<u32, 5>, Option<u32>);
+const SWAP_REMOVE_COPY: SwapRemoveResult =
+    ConstVec::from_array([10, 20, 30, 40, 50]).swap_remove_copy(1);
+const SWAP_REMOVE_COPY_LAST: SwapRemoveResult =
+    ConstVec::from_array([10, 20, 30]).swap_remove_copy(2);
+const SWAP_REMOVE_COPY_OUT_OF_BOUNDS: SwapRemoveResult =
+    ConstVec::from_array([10, 20, 30]).swap_remove_copy(3);
+#[test]
+fn test_swap_remove_copy() {
+    assert_eq!(SWAP_REMOVE_COPY.0, [10, 50, 30, 40]);
+    assert_eq!(SWAP_REMOVE_COPY.1, Some(20));
+    assert_eq!(SWAP_REMOVE_COPY_LAST.0, [10, 20]);
+    assert_eq!(SWAP_REMOVE_COPY_LAST.1, Some(30));
+    assert_eq!(SWAP_REMOVE_COPY_OUT_OF_BOUNDS.0, [10, 20, 30]);
+    assert_eq!(SWAP_REMOVE_COPY_OUT_OF_BOUNDS.1, None);
+}
+
+const fn try_remove() -> (ConstVec<u32, 10>, Option<u32>) {
+    ConstVec::new()
+        .push(10)
+        .push(20)
+        .push(30)
+        .push(40)
+        .try_remove(1)
+}
+const TRY_REMOVE_TEST: (ConstVec<u32, 10>, Option<u32>) = try_remove();
+#[test]
+fn test_try_remove() {
+    assert_eq!(TRY_REMOVE_TEST.0, [10, 30, 40]);
+    assert_eq!(TRY_REMOVE_TEST.1, Some(20));
+}
+
+const TRY_REMOVE_OUT_OF_BOUNDS: (ConstVec<u32, 10>, Option<u32>) =
+    ConstVec::new().push(10).try_remove(1);
+#[test]
+fn test_try_remove_out_of_bounds() {
+    assert_eq!(TRY_REMOVE_OUT_OF_BOUNDS.0, [10]);
+    assert_eq!(TRY_REMOVE_OUT_OF_BOUNDS.1, None);
+}
+
+type InsertResult = Result<ConstVec<u32, 5>, const_push::CapacityError<u32, 5>>;
+const fn try_insert(ix: usize) -> InsertResult {
+    ConstVec::new()
+        .push(10)
+        .push(20)
+        .push(30)
+        .try_insert(ix, 99)
+}
+const TRY_INSERT_FRONT: InsertResult = try_insert(0);
+const TRY_INSERT_MIDDLE: InsertResult = try_insert(1);
+const TRY_INSERT_END: InsertResult = try_insert(3);
+#[test]
+fn test_try_insert() {
+    assert_eq!(TRY_INSERT_FRONT.unwrap(), [99, 10, 20, 30]);
+    assert_eq!(TRY_INSERT_MIDDLE.unwrap(), [10, 99, 20, 30]);
+    assert_eq!(TRY_INSERT_END.unwrap(), [10, 20, 30, 99]);
+}
+
+const TRY_INSERT_FULL: InsertResult = ConstVec::from_array([1, 2, 3, 4, 5]).try_insert(0, 99);
+#[test]
+fn test_try_insert_full() {
+    let error = TRY_INSERT_FULL.unwrap_err();
+    assert_eq!(error.vector, [1, 2, 3, 4, 5]);
+    assert_eq!(error.rejected, 99);
     assert_eq!(
-        TRY_SWAP_REMOVE_TEST.0.len(),
-        3,
-        "Improper array length (list is {:?}",
-        TRY_SWAP_REMOVE_TEST.0
+        format!("{error:?}"),
+        "CapacityError { capacity: 5, rejected: 1 }"
     );
-    (&TRY_SWAP_REMOVE_TEST.0)
-        .into_iter()
-        .copied()
-        .zip([10, 40, 30])
-        .enumerate()
-        .for_each(|(ix, (const_constructed, test))| {
-            assert_eq!(
-                const_constructed, test,
-                "Elements at index {ix} do not match (list is {:?})",
-                TRY_SWAP_REMOVE_TEST.0
-            )
-        });
+}
 
-    assert_eq!(TRY_SWAP_REMOVE_TEST.1, Some(20))
+#[test]
+#[should_panic(expected = "insertion index is past the end of the vector")]
+fn test_try_insert_past_end() {
+    let _ = ConstVec::<u32, 5>::new().push(10).try_insert(2, 99);
+}
+
+const TRY_SWAP: ConstVec<u32, 4> = ConstVec::new().push(10).push(20).push(30).try_swap(0, 2);
+const TRY_SWAP_SAME: ConstVec<u32, 4> = ConstVec::new().push(10).push(20).try_swap(1, 1);
+#[test]
+fn test_try_swap() {
+    assert_eq!(TRY_SWAP, [30, 20, 10]);
+    assert_eq!(TRY_SWAP_SAME, [10, 20]);
+}
+
+#[test]
+#[should_panic(expected = "swap index is out of bounds")]
+fn test_try_swap_out_of_bounds() {
+    let _ = ConstVec::<u32, 4>::new().push(10).push(20).try_swap(0, 2);
+}
+
+const REVERSE: ConstVec<u32, 8> = ConstVec::from_array([1, 2, 3, 4, 5]).reverse();
+const REVERSE_EVEN: ConstVec<u32, 8> = ConstVec::from_array([1, 2, 3, 4]).reverse();
+const REVERSE_SINGLE: ConstVec<u32, 8> = ConstVec::from_array([1]).reverse();
+const REVERSE_EMPTY: ConstVec<u32, 8> = ConstVec::new().reverse();
+const REVERSE_ZST: ConstVec<(), 8> = ConstVec::from_array([(), (), ()]).reverse();
+#[test]
+fn test_reverse() {
+    assert_eq!(REVERSE, [5, 4, 3, 2, 1]);
+    assert_eq!(REVERSE_EVEN, [4, 3, 2, 1]);
+    assert_eq!(REVERSE_SINGLE, [1]);
+    assert!(REVERSE_EMPTY.is_empty());
+    assert_eq!(REVERSE_ZST.len(), 3);
+}
+
+#[test]
+fn test_resize() {
+    let mut c = ConstVec::<u32, 8>::from_array([1, 2]);
+    c.resize(5, 9);
+    assert_eq!(c, [1, 2, 9, 9, 9]);
+    c.resize(1, 0);
+    assert_eq!(c, [1]);
+
+    let mut next = 0;
+    c.resize_with(4, || {
+        next += 1;
+        next
+    });
+    assert_eq!(c, [1, 1, 2, 3]);
+    c.resize_with(2, || unreachable!());
+    assert_eq!(c, [1, 1]);
+}
+
+#[test]
+#[should_panic(expected = "capacity 8 exceeded")]
+fn test_resize_past_capacity() {
+    ConstVec::<u32, 8>::new().resize(9, 0);
+}
+
+#[test]
+fn test_splice() {
+    // shorter replacement
+    let mut c = ConstVec::<u32, 8>::from_array([1, 2, 3, 4, 5]);
+    let removed: Vec<_> = c.splice(1..4, [9]).collect();
+    assert_eq!(removed, [2, 3, 4]);
+    assert_eq!(c, [1, 9, 5]);
+
+    // longer replacement
+    let removed: Vec<_> = c.splice(1..=1, [6, 7, 8]).collect();
+    assert_eq!(removed, [9]);
+    assert_eq!(c, [1, 6, 7, 8, 5]);
+
+    // empty range inserts
+    assert_eq!(c.splice(5.., [0, 0]).count(), 0);
+    assert_eq!(c, [1, 6, 7, 8, 5, 0, 0]);
+
+    let mut c = ConstVec::<String, 4>::from_array(["a", "b", "c"].map(String::from));
+    let removed: Vec<_> = c.splice(..2, ["x".to_string()]).collect();
+    assert_eq!(removed, ["a", "b"]);
+    assert_eq!(c.as_slice(), ["x", "c"]);
+}
+
+#[test]
+#[should_panic(expected = "capacity 4 exceeded")]
+fn test_splice_past_capacity() {
+    let mut c = ConstVec::<u32, 4>::from_array([1, 2, 3]);
+    c.splice(1..2, [7, 8, 9]);
+}
+
+#[test]
+#[should_panic(expected = "splice range end 4 is out of range for length 3")]
+fn test_splice_out_of_bounds() {
+    let mut c = ConstVec::<u32, 4>::from_array([1, 2, 3]);
+    c.splice(2..4, []);
+}
+
+#[test]
+#[should_panic(expected = "is out of range for length 3")]
+fn test_splice_inclusive_max() {
+    let mut c = ConstVec::<u32, 4>::from_array([1, 2, 3]);
+    c.splice(2..=usize::MAX, []);
+}
+
+#[test]
+fn test_append() {
+    let mut c = ConstVec::<String, 8>::from_array(["a".to_string()]);
+    let mut other = ConstVec::<String, 4>::from_array(["b", "c"].map(String::from));
+    c.append(&mut other);
+    assert_eq!(c.as_slice(), ["a", "b", "c"]);
+    assert!(other.is_empty());
+}
+
+#[test]
+fn test_append_overflow() {
+    let mut c = ConstVec::<u32, 3>::from_array([1, 2]);
+    let mut other = ConstVec::<u32, 4>::from_array([3, 4]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| c.append(&mut other)));
+    assert!(result.is_err());
+    assert_eq!(c, [1, 2]);
+    assert_eq!(other, [3, 4]);
+}
+
+#[test]
+fn test_dedup() {
+    let mut c = ConstVec::<u32, 8>::from_array([1, 1, 2, 3, 3, 3, 1]);
+    c.dedup();
+    assert_eq!(c, [1, 2, 3, 1]);
+
+    let mut c = ConstVec::<i32, 8>::from_array([1, -1, 2, -2, -2, 3]);
+    c.dedup_by_key(|x| x.abs());
+    assert_eq!(c, [1, 2, 3]);
+
+    let mut c = ConstVec::<u32, 8>::new();
+    c.dedup();
+    assert!(c.is_empty());
+}
+
+const TRUNCATE: ConstVec<u32, 8> = ConstVec::from_array([1, 2, 3, 4]).truncate_const(2);
+const TRUNCATE_NOOP: ConstVec<u32, 8> = ConstVec::from_array([1, 2, 3, 4]).truncate_const(6);
+#[test]
+fn test_truncate_const() {
+    assert_eq!(TRUNCATE, [1, 2]);
+    assert_eq!(TRUNCATE_NOOP, [1, 2, 3, 4]);
+}
+
+#[test]
+fn test_truncate() {
+    let mut c = ConstVec::<String, 8>::new()
+        .push("a".to_string())
+        .push("b".to_string())
+        .push("c".to_string());
+    c.truncate(5);
+    assert_eq!(c.as_slice(), ["a", "b", "c"]);
+    c.truncate(1);
+    assert_eq!(c.as_slice(), ["a"]);
+}
+
+const TRY_SET_LEN: Result<ConstVec<u32, 8>, ConstVec<u32, 8>> =
+    ConstVec::from_array([1, 2, 3, 4]).try_set_len(1);
+const TRY_SET_LEN_GROW: Result<ConstVec<u32, 8>, ConstVec<u32, 8>> =
+    ConstVec::from_array([1, 2, 3, 4]).try_set_len(5);
+#[test]
+fn test_try_set_len() {
+    assert_eq!(TRY_SET_LEN.unwrap(), [1]);
+    // growing would expose uninitialized slots
+    assert_eq!(TRY_SET_LEN_GROW.unwrap_err(), [1, 2, 3, 4]);
+    assert!(ConstVec::<u32, 8>::new().try_set_len(9).is_err());
+}
+
+const CLEAR: ConstVec<u32, 8> = ConstVec::from_array([1, 2, 3]).clear_const();
+#[test]
+fn test_clear_const() {
+    assert!(CLEAR.is_empty());
+}
+
+const fn capacity_info() -> (usize, usize, bool, bool) {
+    let partial = ConstVec::<u32, 8>::from_array([1, 2, 3]);
+    let full = ConstVec::<u32, 3>::from_array([1, 2, 3]);
+    let info = (
+        partial.capacity(),
+        partial.remaining_capacity(),
+        partial.is_full(),
+        full.is_full(),
+    );
+    core::mem::forget(partial);
+    core::mem::forget(full);
+    info
+}
+const CAPACITY_INFO: (usize, usize, bool, bool) = capacity_info();
+#[test]
+fn test_capacity_info() {
+    assert_eq!(CAPACITY_INFO, (8, 5, false, true));
+}
+
+const fn first_through_ptr() -> u32 {
+    let c = ConstVec::<u32, 4>::from_array([7, 8]);
+    let first = unsafe { *c.as_ptr() };
+    core::mem::forget(c);
+    first
+}
+const FIRST_THROUGH_PTR: u32 = first_through_ptr();
+#[test]
+fn test_as_ptr() {
+    assert_eq!(FIRST_THROUGH_PTR, 7);
+
+    let mut c = ConstVec::<u32, 4>::from_array([7, 8]);
+    unsafe {
+        assert_eq!(*c.as_ptr().add(1), 8);
+        c.as_mut_ptr().add(2).write(9);
+        c = c.set_len(3);
+    }
+    assert_eq!(c, [7, 8, 9]);
+}
+
+const fn get_pair(a: usize, b: usize) -> Option<(u32, u32)> {
+    let c = ConstVec::<u32, 4>::from_array([10, 20, 30]);
+    let pair = match c.get_pair(a, b) {
+        Some((a, b)) => Some((*a, *b)),
+        None => None,
+    };
+    core::mem::forget(c);
+    pair
+}
+const GET_PAIR: Option<(u32, u32)> = get_pair(0, 2);
+const GET_PAIR_OUT_OF_BOUNDS: Option<(u32, u32)> = get_pair(0, 3);
+#[test]
+fn test_get_pair() {
+    assert_eq!(GET_PAIR, Some((10, 30)));
+    assert_eq!(GET_PAIR_OUT_OF_BOUNDS, None);
+    assert_eq!(get_pair(1, 1), Some((20, 20)));
+}
+
+#[test]
+fn test_get_non_copy() {
+    let mut c = ConstVec::<String, 4>::from_array(["a", "b"].map(String::from));
+    assert_eq!(c.get(1).map(String::as_str), Some("b"));
+    assert_eq!(c.get(2), None);
+    c.get_mut(0).unwrap().push('!');
+    assert_eq!(c.as_slice(), ["a!", "b"]);
+    assert_eq!(c.get_mut(2), None);
+}
+
+#[test]
+fn test_slice_spans_buffer() {
+    // the slice pointer must be valid for every element, not just the first
+    let mut c = ConstVec::<String, 4>::from_array(["a", "b", "c"].map(String::from));
+    c.as_mut_slice()[2].push('!');
+    let (c, last) = c.pop();
+    assert_eq!(last.as_deref(), Some("c!"));
+    assert_eq!(c.as_slice(), ["a", "b"]);
+}
+
+#[test]
+fn test_spare_capacity_mut() {
+    let mut c = ConstVec::<String, 4>::new().push("a".to_string());
+    let spare = c.spare_capacity_mut();
+    assert_eq!(spare.len(), 3);
+    spare[0].write("b".to_string());
+    spare[1].write("c".to_string());
+    c = unsafe { c.set_len(3) };
+    assert_eq!(c.as_slice(), ["a", "b", "c"]);
+    assert_eq!(c.spare_capacity_mut().len(), 1);
+}
+
+const fn first_last<const N: usize>(xs: [u32; N]) -> (Option<u32>, Option<u32>) {
+    let c = ConstVec::<u32, 4>::from_array(xs);
+    let ends = (c.first().copied(), c.last().copied());
+    core::mem::forget(c);
+    ends
+}
+const FIRST_LAST: (Option<u32>, Option<u32>) = first_last([10, 20, 30]);
+const FIRST_LAST_SINGLE: (Option<u32>, Option<u32>) = first_last([10]);
+const FIRST_LAST_EMPTY: (Option<u32>, Option<u32>) = first_last([]);
+#[test]
+fn test_first_last() {
+    assert_eq!(FIRST_LAST, (Some(10), Some(30)));
+    assert_eq!(FIRST_LAST_SINGLE, (Some(10), Some(10)));
+    assert_eq!(FIRST_LAST_EMPTY, (None, None));
+}
+
+#[test]
+fn test_first_last_mut() {
+    let mut c = ConstVec::<u32, 4>::from_array([10, 20, 30]);
+    *c.first_mut().unwrap() += 1;
+    *c.last_mut().unwrap() += 2;
+    assert_eq!(c, [11, 20, 32]);
+
+    let mut empty = ConstVec::<u32, 4>::new();
+    assert!(empty.first_mut().is_none());
+    assert!(empty.last_mut().is_none());
+}
+
+type AppendResult = Result<ConstVec<u32, 10>, const_push::CapacityError<u32, 10, ConstVec<u32, 4>>>;
+const TRY_APPEND: AppendResult =
+    ConstVec::from_array([1, 2]).try_append(ConstVec::<_, 4>::from_array([3, 4]));
+const TRY_APPEND_OVERFLOW: AppendResult =
+    ConstVec::from_array([1, 2, 3, 4, 5, 6, 7, 8]).try_append(ConstVec::from_array([9, 10, 11]));
+#[test]
+fn test_try_append() {
+    assert_eq!(TRY_APPEND.unwrap(), [1, 2, 3, 4]);
+
+    let error = TRY_APPEND_OVERFLOW.unwrap_err();
+    assert_eq!(error.vector, [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(error.rejected, [9, 10, 11]);
+}
+
+type ExtendResult = Result<ConstVec<u32, 4>, const_push::CapacityError<u32, 4, &'static [u32]>>;
+const TRY_EXTEND_FROM_SLICE: ExtendResult =
+    ConstVec::new().push(1).try_extend_from_slice(&[2, 3, 4]);
+const TRY_EXTEND_FROM_SLICE_OVERFLOW: ExtendResult = ConstVec::new()
+    .push(1)
+    .push(2)
+    .try_extend_from_slice(&[3, 4, 5]);
+#[test]
+fn test_try_extend_from_slice() {
+    assert_eq!(TRY_EXTEND_FROM_SLICE.unwrap(), [1, 2, 3, 4]);
+
+    let error = TRY_EXTEND_FROM_SLICE_OVERFLOW.unwrap_err();
+    assert_eq!(error.vector, [1, 2]);
+    assert_eq!(error.rejected, [3, 4, 5]);
+    assert_eq!(
+        format!("{error:?}"),
+        "CapacityError { capacity: 4, rejected: 3 }"
+    );
+}
+
+#[test]
+fn test_map_runtime() {
+    let mut seen = Vec::new();
+    let strings: ConstVec<String, 4> = ConstVec::<u32, 4>::from_array([1, 2, 3]).map_runtime(|x| {
+        seen.push(x);
+        format!("#{x}")
+    });
+    assert_eq!(
+        strings,
+        ["#1".to_string(), "#2".to_string(), "#3".to_string()]
+    );
+    assert_eq!(strings.capacity(), 4);
+    assert_eq!(seen, [1, 2, 3]);
+}
+
+type SplitResult = (ConstVec<u32, 6>, ConstVec<u32, 6>);
+const SPLIT_AT: SplitResult = ConstVec::from_array([1, 2, 3, 4]).split_at(2);
+const SPLIT_AT_START: SplitResult = ConstVec::from_array([1, 2, 3, 4]).split_at(0);
+const SPLIT_AT_END: SplitResult = ConstVec::from_array([1, 2, 3, 4]).split_at(4);
+#[test]
+fn test_split_at() {
+    let (head, tail) = SPLIT_AT;
+    assert_eq!(head, [1, 2]);
+    assert_eq!(tail, [3, 4]);
+
+    let (head, tail) = SPLIT_AT_START;
+    assert!(head.is_empty());
+    assert_eq!(tail, [1, 2, 3, 4]);
+
+    let (head, tail) = SPLIT_AT_END;
+    assert_eq!(head, [1, 2, 3, 4]);
+    assert!(tail.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "split index is past the end of the vector")]
+fn test_split_at_past_end() {
+    let _ = ConstVec::<u32, 6>::from_array([1, 2]).split_at(3);
+}
+
+const CHUNKED: ConstVec<ConstVec<u32, 2>, 3> =
+    ConstVec::<u32, 5>::from_array([1, 2, 3, 4, 5]).chunked();
+#[test]
+fn test_chunked() {
+    assert_eq!(CHUNKED.len(), 3);
+    assert_eq!(CHUNKED[0], [1, 2]);
+    assert_eq!(CHUNKED[1], [3, 4]);
+    assert_eq!(CHUNKED[2], [5]);
+
+    let strings = ConstVec::<String, 4>::from_array(["a", "b", "c", "d"].map(String::from));
+    let chunks = strings.chunked::<2, 2>();
+    assert_eq!(chunks[0].as_slice(), ["a", "b"]);
+    assert_eq!(chunks[1].as_slice(), ["c", "d"]);
+}
+
+#[test]
+#[should_panic(expected = "the chunks exceed the capacity")]
+fn test_chunked_past_capacity() {
+    let _ = ConstVec::<u32, 5>::from_array([1, 2, 3, 4, 5]).chunked::<2, 2>();
+}
+
+const ZIPPED: ConstVec<(u32, char), 4> =
+    ConstVec::from_array([1, 2, 3]).zip(ConstVec::<_, 3>::from_array(['a', 'b', 'c']));
+const ZIPPED_SHORTER: ConstVec<(u32, char), 4> =
+    ConstVec::from_array([1, 2, 3]).zip(ConstVec::<_, 6>::from_array(['a', 'b']));
+#[test]
+fn test_zip() {
+    assert_eq!(ZIPPED, [(1, 'a'), (2, 'b'), (3, 'c')]);
+    assert_eq!(ZIPPED_SHORTER, [(1, 'a'), (2, 'b')]);
+
+    let keys = ConstVec::<String, 2>::from_array(["x", "y"].map(String::from));
+    let zipped = keys.zip(ConstVec::<u32, 2>::from_array([1, 2]));
+    assert_eq!(zipped[1], ("y".to_string(), 2));
+}
+
+const UNZIPPED: (ConstVec<u32, 4>, ConstVec<char, 4>) =
+    ConstVec::from_array([(1, 'a'), (2, 'b')]).unzip();
+#[test]
+fn test_unzip() {
+    let (numbers, letters) = UNZIPPED;
+    assert_eq!(numbers, [1, 2]);
+    assert_eq!(letters, ['a', 'b']);
+
+    let pairs =
+        ConstVec::<(String, u32), 4>::from_array([("x".to_string(), 1), ("y".to_string(), 2)]);
+    let (keys, values) = pairs.unzip();
+    assert_eq!(keys.as_slice(), ["x", "y"]);
+    assert_eq!(values, [1, 2]);
+}
+
+type SplitEndResult = Option<(u32, ConstVec<u32, 4>)>;
+const SPLIT_FIRST: SplitEndResult = ConstVec::from_array([1, 2, 3]).split_first();
+const SPLIT_FIRST_EMPTY: SplitEndResult = ConstVec::new().split_first();
+const SPLIT_LAST: SplitEndResult = ConstVec::from_array([1, 2, 3]).split_last();
+const SPLIT_LAST_EMPTY: SplitEndResult = ConstVec::new().split_last();
+#[test]
+fn test_split_first_last() {
+    let (first, rest) = SPLIT_FIRST.unwrap();
+    assert_eq!(first, 1);
+    assert_eq!(rest, [2, 3]);
+    assert!(SPLIT_FIRST_EMPTY.is_none());
+
+    let (last, rest) = SPLIT_LAST.unwrap();
+    assert_eq!(last, 3);
+    assert_eq!(rest, [1, 2]);
+    assert!(SPLIT_LAST_EMPTY.is_none());
+}
+
+#[test]
+fn test_layout() {
+    // the length followed by the buffer, with no space taken by the storage type
+    assert_eq!(
+        size_of::<ConstVec<u64, 4>>(),
+        size_of::<usize>() + size_of::<[u64; 4]>()
+    );
+    assert_eq!(size_of::<ConstVec<(), 16>>(), size_of::<usize>());
 }
@@ -0,0 +1,36 @@
+#![cfg(feature = "bytemuck")]
+
+use bytemuck::PodCastError;
+use const_push::{ConstVec, FromBytesError};
+
+const BYTES: ConstVec<u32, 4> = ConstVec::new().push(1).push(0x0201).push(u32::MAX);
+#[test]
+fn test_bytes_round_trip() {
+    let source = BYTES;
+    let bytes = source.as_bytes();
+    assert_eq!(bytes.len(), 12);
+    assert_eq!(bytes[..8], [1, 0, 0, 0, 1, 2, 0, 0]);
+
+    assert_eq!(ConstVec::<u32, 4>::try_from_bytes(bytes).unwrap(), BYTES);
+}
+
+#[test]
+fn test_try_from_bytes_invalid() {
+    let source = ConstVec::<u32, 8>::from_array([1, 2, 3, 4, 5]);
+    let bytes = source.as_bytes();
+
+    assert_eq!(
+        ConstVec::<u32, 4>::try_from_bytes(&bytes[..6]),
+        Err(FromBytesError::Cast(PodCastError::OutputSliceWouldHaveSlop))
+    );
+    assert_eq!(
+        ConstVec::<u32, 4>::try_from_bytes(&bytes[1..5]),
+        Err(FromBytesError::Cast(
+            PodCastError::TargetAlignmentGreaterAndInputNotAligned
+        ))
+    );
+    assert_eq!(
+        ConstVec::<u32, 4>::try_from_bytes(bytes),
+        Err(FromBytesError::Capacity)
+    );
+}
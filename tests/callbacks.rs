@@ -0,0 +1,269 @@
+#![cfg(feature = "const-callbacks")]
+#![feature(const_precise_live_drops, const_trait_impl)]
+
+use const_push::ConstVec;
+
+#[cfg(feature = "fake-move")]
+const fn double(x: u32) -> u32 {
+    x * 2
+}
+
+#[cfg(feature = "fake-move")]
+const MAP: ConstVec<u32, 4> = ConstVec::from_array([1, 2, 3]).map(double);
+#[cfg(feature = "fake-move")]
+const MAP_EMPTY: ConstVec<u32, 4> = ConstVec::<u32, 4>::new().map(double);
+#[cfg(feature = "fake-move")]
+#[test]
+fn test_map() {
+    assert_eq!(MAP, [2, 4, 6]);
+    assert!(MAP_EMPTY.is_empty());
+
+    let offset = 10;
+    let strings = ConstVec::<u32, 4>::from_array([1, 2]).map(|x| (x + offset).to_string());
+    assert_eq!(strings, ["11".to_string(), "12".to_string()]);
+}
+
+#[cfg(feature = "fake-move")]
+const fn is_even(x: &u32) -> bool {
+    x.is_multiple_of(2)
+}
+
+#[cfg(feature = "fake-move")]
+const PARTITION: (ConstVec<u32, 4>, ConstVec<u32, 4>) =
+    ConstVec::from_array([1, 2, 3, 4]).partition(is_even);
+#[cfg(feature = "fake-move")]
+#[test]
+fn test_partition() {
+    let (evens, odds) = PARTITION;
+    assert_eq!(evens, [2, 4]);
+    assert_eq!(odds, [1, 3]);
+
+    let strings = ConstVec::<String, 4>::from_array(["a", "bb", "cc", "d"].map(String::from));
+    let (long, short) = strings.partition(|s| s.len() > 1);
+    assert_eq!(long.as_slice(), ["bb", "cc"]);
+    assert_eq!(short.as_slice(), ["a", "d"]);
+}
+
+const fn sq(i: usize) -> u32 {
+    (i * i) as u32
+}
+
+const FROM_FN: ConstVec<u32, 6> = ConstVec::from_fn::<4, _>(sq);
+#[test]
+fn test_from_fn() {
+    assert_eq!(FROM_FN, [0, 1, 4, 9]);
+    assert_eq!(FROM_FN.capacity(), 6);
+
+    let strings = ConstVec::<String, 3>::from_fn::<3, _>(|i| i.to_string());
+    assert_eq!(strings.as_slice(), ["0", "1", "2"]);
+}
+
+#[cfg(feature = "fake-move")]
+const fn compare_u32(a: &u32, b: &u32) -> core::cmp::Ordering {
+    if *a < *b {
+        core::cmp::Ordering::Less
+    } else if *a > *b {
+        core::cmp::Ordering::Greater
+    } else {
+        core::cmp::Ordering::Equal
+    }
+}
+
+#[cfg(feature = "fake-move")]
+const SORTED: ConstVec<u32, 4> = ConstVec::from_array([3, 1, 2]).sorted_by(compare_u32);
+#[cfg(feature = "fake-move")]
+const SORTED_DUPLICATES: ConstVec<u32, 8> =
+    ConstVec::from_array([5, 1, 4, 1, 5, 9, 2]).sorted_by(compare_u32);
+#[cfg(feature = "fake-move")]
+const SORTED_EMPTY: ConstVec<u32, 4> = ConstVec::new().sorted_by(compare_u32);
+#[cfg(feature = "fake-move")]
+#[test]
+fn test_sorted_by() {
+    assert_eq!(SORTED, [1, 2, 3]);
+    assert_eq!(SORTED_DUPLICATES, [1, 1, 2, 4, 5, 5, 9]);
+    assert!(SORTED_EMPTY.is_empty());
+
+    // stable: equal keys keep their order
+    let pairs = ConstVec::<(u32, &str), 4>::from_array([(2, "a"), (1, "b"), (2, "c"), (1, "d")])
+        .sorted_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(pairs, [(1, "b"), (1, "d"), (2, "a"), (2, "c")]);
+}
+
+const fn eq_slice() -> (bool, bool, bool) {
+    let c = ConstVec::<u32, 8>::from_array([1, 2, 3]);
+    let result = (
+        c.eq_slice(&[1, 2, 3]),
+        c.eq_slice(&[1, 2, 4]),
+        c.eq_slice(&[1, 2]),
+    );
+    core::mem::forget(c);
+    result
+}
+const EQ_SLICE: (bool, bool, bool) = eq_slice();
+#[test]
+fn test_eq_slice() {
+    assert_eq!(EQ_SLICE, (true, false, false));
+
+    let strings = ConstVec::<String, 4>::from_array(["a".to_string()]);
+    assert!(strings.eq_slice(&["a".to_string()]));
+}
+
+const fn add(a: u32, b: &u32) -> u32 {
+    a + *b
+}
+
+const fn fold() -> (u32, u32) {
+    let c = ConstVec::<u32, 8>::from_array([1, 2, 3, 4]);
+    let empty = ConstVec::<u32, 8>::new();
+    let result = (c.fold(0, add), empty.fold(10, add));
+    core::mem::forget(c);
+    core::mem::forget(empty);
+    result
+}
+const FOLD: (u32, u32) = fold();
+#[test]
+fn test_fold() {
+    assert_eq!(FOLD, (10, 10));
+
+    let strings = ConstVec::<&str, 4>::from_array(["a", "b", "c"]);
+    assert_eq!(strings.fold(String::new(), |acc, s| acc + s), "abc");
+}
+
+const fn is_ordered_pair(pair: &(u32, u32)) -> bool {
+    pair.0 <= pair.1
+}
+
+const fn is_empty_pair(pair: &(u32, u32)) -> bool {
+    pair.0 == pair.1
+}
+
+const fn all_any(xs: [(u32, u32); 3]) -> (bool, bool) {
+    let c = ConstVec::<(u32, u32), 4>::from_array(xs);
+    let result = (c.all(is_ordered_pair), c.any(is_empty_pair));
+    core::mem::forget(c);
+    result
+}
+const ALL_ANY: (bool, bool) = all_any([(0, 4), (4, 4), (5, 9)]);
+const ALL_ANY_FAILING: (bool, bool) = all_any([(0, 4), (9, 5), (6, 7)]);
+const _: () = assert!(ALL_ANY.0);
+#[test]
+fn test_all_any() {
+    assert_eq!(ALL_ANY, (true, true));
+    assert_eq!(ALL_ANY_FAILING, (false, false));
+
+    let empty = ConstVec::<(u32, u32), 4>::new();
+    assert!(empty.all(is_empty_pair));
+    assert!(!empty.any(is_ordered_pair));
+}
+
+const fn search(target: u32) -> Result<usize, usize> {
+    let c = ConstVec::<u32, 8>::from_array([2, 3, 5, 7, 11, 13]);
+    let result = c.binary_search_const(&target);
+    core::mem::forget(c);
+    result
+}
+const SEARCH_FOUND: Result<usize, usize> = search(7);
+const SEARCH_MISSING: Result<usize, usize> = search(6);
+#[test]
+fn test_binary_search_const() {
+    assert_eq!(SEARCH_FOUND, Ok(3));
+    assert_eq!(SEARCH_MISSING, Err(3));
+
+    // agrees with the slice method
+    let c = ConstVec::<u32, 8>::from_array([2, 3, 5, 7, 11, 13]);
+    for target in 0..15 {
+        assert_eq!(c.binary_search_const(&target), c.binary_search(&target));
+    }
+    assert_eq!(ConstVec::<u32, 8>::new().binary_search_const(&1), Err(0));
+}
+
+const fn is_sorted(xs: [u32; 5]) -> bool {
+    let c = ConstVec::<u32, 5>::from_array_exact(xs);
+    let result = c.is_sorted();
+    core::mem::forget(c);
+    result
+}
+const IS_SORTED: (bool, bool) = (is_sorted([1, 2, 2, 5, 9]), is_sorted([1, 2, 5, 2, 9]));
+#[test]
+fn test_is_sorted() {
+    assert_eq!(IS_SORTED, (true, false));
+    assert!(ConstVec::<u32, 5>::new().is_sorted());
+    // matches the slice method for partially ordered elements
+    assert!(!ConstVec::<f32, 4>::from_array([1.0, f32::NAN, 2.0]).is_sorted());
+}
+
+const fn is_zero(x: &u32) -> bool {
+    *x == 0
+}
+
+const fn count_zeros(xs: [u32; 4]) -> (usize, usize) {
+    let c = ConstVec::<u32, 8>::from_array(xs);
+    let result = (c.count_leading(is_zero), c.count_trailing(is_zero));
+    core::mem::forget(c);
+    result
+}
+const COUNT_ZEROS: (usize, usize) = count_zeros([0, 0, 5, 0]);
+const COUNT_ALL_ZEROS: (usize, usize) = count_zeros([0; 4]);
+#[test]
+fn test_count_leading_trailing() {
+    assert_eq!(COUNT_ZEROS, (2, 1));
+    assert_eq!(COUNT_ALL_ZEROS, (4, 4));
+    assert_eq!(ConstVec::<u32, 4>::new().count_leading(is_zero), 0);
+}
+
+#[cfg(feature = "fake-move")]
+const TRIM_LEADING: ConstVec<u32, 4> = ConstVec::from_array([0, 0, 5, 0]).trim_leading(is_zero);
+#[cfg(feature = "fake-move")]
+const TRIM_TRAILING: ConstVec<u32, 4> = ConstVec::from_array([0, 0, 5, 0]).trim_trailing(is_zero);
+#[cfg(feature = "fake-move")]
+const TRIM_ALL: ConstVec<u32, 4> = ConstVec::from_array([0, 0]).trim_leading(is_zero);
+#[cfg(feature = "fake-move")]
+#[test]
+fn test_trim() {
+    assert_eq!(TRIM_LEADING, [5, 0]);
+    assert_eq!(TRIM_TRAILING, [0, 0, 5]);
+    assert!(TRIM_ALL.is_empty());
+
+    let padded = ConstVec::<&str, 8>::from_array(["", "a", "", "b", ""]);
+    let trimmed = padded
+        .trim_leading(|s| s.is_empty())
+        .trim_trailing(|s| s.is_empty());
+    assert_eq!(trimmed, ["a", "", "b"]);
+}
+
+const DEDUPED: ConstVec<u32, 8> = ConstVec::from_array([1, 1, 2, 2, 2, 3]).dedup_consecutive();
+const DEDUPED_EMPTY: ConstVec<u32, 8> = ConstVec::new().dedup_consecutive();
+#[test]
+fn test_dedup_consecutive() {
+    assert_eq!(DEDUPED, [1, 2, 3]);
+    assert!(DEDUPED_EMPTY.is_empty());
+    // only adjacent duplicates are removed
+    assert_eq!(
+        ConstVec::<u32, 8>::from_array([1, 2, 1, 1]).dedup_consecutive(),
+        [1, 2, 1]
+    );
+}
+
+const fn aggregates(xs: [u32; 5]) -> (Option<u32>, Option<u32>, u32) {
+    let c = ConstVec::<u32, 8>::from_array(xs);
+    let result = (c.max_element().copied(), c.min_element().copied(), c.sum());
+    core::mem::forget(c);
+    result
+}
+const AGGREGATES: (Option<u32>, Option<u32>, u32) = aggregates([3, 1, 4, 1, 5]);
+#[test]
+fn test_aggregates() {
+    assert_eq!(AGGREGATES, (Some(5), Some(1), 14));
+
+    let empty = ConstVec::<i64, 4>::new();
+    assert_eq!(
+        (empty.max_element(), empty.min_element(), empty.sum()),
+        (None, None, 0)
+    );
+
+    // ties resolve like the iterator methods
+    let pairs = ConstVec::<(u8, char), 4>::from_array([(1, 'a'), (2, 'b'), (1, 'c'), (2, 'd')]);
+    assert_eq!(pairs.max_element(), Some(&(2, 'd')));
+    assert_eq!(pairs.iter().max(), Some(&(2, 'd')));
+    assert_eq!(pairs.min_element(), Some(&(1, 'a')));
+}
@@ -0,0 +1,68 @@
+use const_push::ConstVec;
+
+#[test]
+fn test_from_array() {
+    let v: ConstVec<u32, 10> = [1, 2, 3].into();
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(v.capacity(), 10);
+
+    let strings = ConstVec::<String, 2>::from(["a".to_string(), "b".to_string()]);
+    assert!(strings.is_full());
+}
+
+#[test]
+fn test_try_from_slice() {
+    let strings = ["a".to_string(), "b".to_string()];
+    let exact = ConstVec::<String, 2>::try_from(strings.as_slice()).unwrap();
+    assert_eq!(exact.as_slice(), ["a", "b"]);
+
+    let over: Result<ConstVec<u32, 2>, _> = [1, 2, 3].as_slice().try_into();
+    let error = over.unwrap_err();
+    assert_eq!(error.vector, [1, 2]);
+    assert_eq!(error.rejected, [3]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_vec_round_trip() {
+    let c = ConstVec::<String, 4>::new()
+        .push("a".to_string())
+        .push("b".to_string());
+    let v: Vec<String> = c.into();
+    assert_eq!(v, ["a", "b"]);
+
+    let c = ConstVec::<String, 4>::try_from_vec(v).unwrap();
+    assert_eq!(c.as_slice(), ["a", "b"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_try_from_vec_overflow() {
+    let error = ConstVec::<u32, 2>::try_from_vec(vec![1, 2, 3, 4]).unwrap_err();
+    assert_eq!(error.vector, [1, 2]);
+    assert_eq!(error.rejected, [3, 4]);
+}
+
+#[cfg(feature = "tinyvec")]
+#[test]
+fn test_into_tinyvec() {
+    let c = ConstVec::<String, 4>::new()
+        .push("a".to_string())
+        .push("b".to_string());
+    let v: tinyvec::ArrayVec<[String; 4]> = c.into();
+    assert_eq!(v.as_slice(), ["a", "b"]);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_heapless_round_trip() {
+    let c = ConstVec::<String, 4>::new()
+        .push("a".to_string())
+        .push("b".to_string());
+    let v: heapless::Vec<String, 4> = c.into();
+    assert_eq!(v, ["a", "b"]);
+
+    let c = ConstVec::from(v);
+    assert_eq!(c.as_slice(), ["a", "b"]);
+    assert_eq!(c.capacity(), 4);
+}
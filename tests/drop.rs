@@ -0,0 +1,170 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use const_push::ConstVec;
+
+struct DropCounter(&'static AtomicUsize);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+static DROP_LIVE_ELEMS: AtomicUsize = AtomicUsize::new(0);
+#[test]
+fn test_drop_live_elems() {
+    let c = ConstVec::<_, 4>::new()
+        .push(DropCounter(&DROP_LIVE_ELEMS))
+        .push(DropCounter(&DROP_LIVE_ELEMS))
+        .push(DropCounter(&DROP_LIVE_ELEMS));
+    assert_eq!(DROP_LIVE_ELEMS.load(Ordering::SeqCst), 0);
+    drop(c);
+    assert_eq!(DROP_LIVE_ELEMS.load(Ordering::SeqCst), 3);
+}
+
+static DROP_EMPTY: AtomicUsize = AtomicUsize::new(0);
+#[test]
+fn test_drop_empty() {
+    let mut c = ConstVec::<_, 4>::new()
+        .push(DropCounter(&DROP_EMPTY))
+        .push(DropCounter(&DROP_EMPTY));
+    c.truncate(0);
+    assert_eq!(DROP_EMPTY.load(Ordering::SeqCst), 2);
+    // nothing is left for the vector itself to drop
+    drop(c);
+    assert_eq!(DROP_EMPTY.load(Ordering::SeqCst), 2);
+}
+
+static DROP_STRINGS: AtomicUsize = AtomicUsize::new(0);
+#[test]
+fn test_drop_strings() {
+    let c = ConstVec::<_, 4>::new()
+        .push(("a".to_string(), DropCounter(&DROP_STRINGS)))
+        .push(("b".to_string(), DropCounter(&DROP_STRINGS)))
+        .push(("c".to_string(), DropCounter(&DROP_STRINGS)));
+    drop(c);
+    assert_eq!(DROP_STRINGS.load(Ordering::SeqCst), 3);
+}
+
+static DROP_TRUNCATED: AtomicUsize = AtomicUsize::new(0);
+#[test]
+fn test_drop_truncated() {
+    let mut c = ConstVec::<_, 4>::new()
+        .push(DropCounter(&DROP_TRUNCATED))
+        .push(DropCounter(&DROP_TRUNCATED))
+        .push(DropCounter(&DROP_TRUNCATED));
+    c.truncate(1);
+    assert_eq!(DROP_TRUNCATED.load(Ordering::SeqCst), 2);
+    drop(c);
+    assert_eq!(DROP_TRUNCATED.load(Ordering::SeqCst), 3);
+}
+
+static DROP_TRUNCATED_STRINGS: AtomicUsize = AtomicUsize::new(0);
+#[test]
+fn test_drop_truncated_strings() {
+    let mut c = ConstVec::<(String, DropCounter), 8>::from_array(
+        ["a", "b", "c", "d"].map(|s| (s.to_string(), DropCounter(&DROP_TRUNCATED_STRINGS))),
+    );
+    c.truncate(1);
+    assert_eq!(DROP_TRUNCATED_STRINGS.load(Ordering::SeqCst), 3);
+    assert_eq!(c[0].0, "a");
+}
+
+static DROP_CLEARED: AtomicUsize = AtomicUsize::new(0);
+#[test]
+fn test_drop_cleared() {
+    let mut c = ConstVec::<_, 4>::new()
+        .push(DropCounter(&DROP_CLEARED))
+        .push(DropCounter(&DROP_CLEARED))
+        .push(DropCounter(&DROP_CLEARED));
+    c.clear();
+    assert!(c.is_empty());
+    assert_eq!(DROP_CLEARED.load(Ordering::SeqCst), 3);
+    drop(c);
+    assert_eq!(DROP_CLEARED.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_clear_strings() {
+    let mut c = ConstVec::<String, 4>::new()
+        .push("a".to_string())
+        .push("b".to_string());
+    c.clear();
+    assert!(c.is_empty());
+}
+
+static DROP_INTO_ITER: AtomicUsize = AtomicUsize::new(0);
+#[test]
+fn test_drop_partially_consumed_into_iter() {
+    let c = ConstVec::<_, 4>::new()
+        .push(DropCounter(&DROP_INTO_ITER))
+        .push(DropCounter(&DROP_INTO_ITER))
+        .push(DropCounter(&DROP_INTO_ITER));
+    let mut iter = c.into_iter();
+    let first = iter.next();
+    assert_eq!(DROP_INTO_ITER.load(Ordering::SeqCst), 0);
+
+    drop(iter);
+    assert_eq!(DROP_INTO_ITER.load(Ordering::SeqCst), 2);
+    drop(first);
+    assert_eq!(DROP_INTO_ITER.load(Ordering::SeqCst), 3);
+}
+
+static DROP_DEDUP: AtomicUsize = AtomicUsize::new(0);
+#[test]
+fn test_drop_dedup() {
+    let mut c = ConstVec::<_, 4>::new()
+        .push((1, DropCounter(&DROP_DEDUP)))
+        .push((1, DropCounter(&DROP_DEDUP)))
+        .push((2, DropCounter(&DROP_DEDUP)));
+    c.dedup_by_key(|(key, _)| *key);
+    assert_eq!(c.len(), 2);
+    assert_eq!(DROP_DEDUP.load(Ordering::SeqCst), 1);
+    drop(c);
+    assert_eq!(DROP_DEDUP.load(Ordering::SeqCst), 3);
+}
+
+static DROP_SPLICE_OVERFLOW: AtomicUsize = AtomicUsize::new(0);
+#[test]
+fn test_drop_splice_overflow() {
+    let counter = || DropCounter(&DROP_SPLICE_OVERFLOW);
+    let result = std::panic::catch_unwind(|| {
+        let mut c = ConstVec::<_, 3>::from_array([counter(), counter()]);
+        c.splice(1..1, [counter(), counter()]);
+    });
+    assert!(result.is_err());
+    // every element is dropped exactly once, whether it made it into the vector or not
+    assert_eq!(DROP_SPLICE_OVERFLOW.load(Ordering::SeqCst), 4);
+}
+
+static DROP_RETAIN_MUT: AtomicUsize = AtomicUsize::new(0);
+#[test]
+fn test_retain_mut() {
+    let mut c =
+        ConstVec::<_, 8>::from_array([1, 5, 2, 7, 3].map(|x| (x, DropCounter(&DROP_RETAIN_MUT))));
+    c.retain_mut(|(x, _)| {
+        *x += 1;
+        *x < 5
+    });
+    assert_eq!(c.iter().map(|(x, _)| *x).collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(DROP_RETAIN_MUT.load(Ordering::SeqCst), 2);
+    drop(c);
+    assert_eq!(DROP_RETAIN_MUT.load(Ordering::SeqCst), 5);
+}
+
+static DROP_CONST_BUILT: AtomicUsize = AtomicUsize::new(0);
+const CONST_BUILT: ConstVec<DropCounter, 4> = ConstVec::new()
+    .push(DropCounter(&DROP_CONST_BUILT))
+    .push(DropCounter(&DROP_CONST_BUILT))
+    .push(DropCounter(&DROP_CONST_BUILT));
+#[test]
+fn test_drop_const_built() {
+    // each use of the const is a fresh vector, and only its live elements are dropped
+    drop(CONST_BUILT);
+    assert_eq!(DROP_CONST_BUILT.load(Ordering::SeqCst), 3);
+    let (c, last) = CONST_BUILT.pop();
+    drop(last);
+    assert_eq!(DROP_CONST_BUILT.load(Ordering::SeqCst), 4);
+    drop(c);
+    assert_eq!(DROP_CONST_BUILT.load(Ordering::SeqCst), 6);
+}
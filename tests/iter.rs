@@ -0,0 +1,225 @@
+#![cfg_attr(feature = "trusted-len", feature(trusted_len))]
+
+use const_push::ConstVec;
+
+const ITER_MUT: ConstVec<u32, 5> = ConstVec::new().push(1).push(2).push(3).push(4).push(5);
+#[test]
+fn test_iter_mut_doubles() {
+    let mut c = ITER_MUT;
+    for x in &mut c {
+        *x *= 2;
+    }
+    assert_eq!(c, [2, 4, 6, 8, 10]);
+
+    c.iter_mut().for_each(|x| *x /= 2);
+    assert_eq!(c, ITER_MUT);
+}
+
+#[test]
+fn test_into_iter_exact_size() {
+    let mut iter = ITER_MUT.into_iter();
+    assert_eq!(iter.len(), 5);
+    iter.next();
+    assert_eq!(iter.len(), 4);
+
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.len(), 0);
+    // fused, so it keeps returning None once exhausted
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+const fn const_iter_sum(c: &ConstVec<u32, 5>) -> u32 {
+    let mut iter = c.iter();
+    let mut sum = 0;
+    while let Some(x) = iter.next_const() {
+        sum += *x;
+    }
+    sum
+}
+const fn const_iter_sum_test() -> u32 {
+    let c = ITER_MUT;
+    let sum = const_iter_sum(&c);
+    core::mem::forget(c);
+    sum
+}
+const CONST_ITER_SUM: u32 = const_iter_sum_test();
+#[test]
+fn test_const_iter() {
+    assert_eq!(CONST_ITER_SUM, 15);
+}
+
+#[test]
+fn test_const_vec_iter() {
+    let c = ITER_MUT;
+    let mut iter: const_push::ConstVecIter<'_, u32, 5> = c.iter();
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.len(), 4);
+    assert!(iter.copied().eq([2, 3, 4, 5]));
+}
+
+const fn const_iter_last_two(c: &ConstVec<u32, 5>) -> (u32, u32) {
+    let mut iter = c.iter();
+    match (iter.next_back_const(), iter.next_back_const()) {
+        (Some(a), Some(b)) => (*a, *b),
+        _ => panic!(),
+    }
+}
+const fn const_iter_back_test() -> (u32, u32) {
+    let c = ITER_MUT;
+    let last_two = const_iter_last_two(&c);
+    core::mem::forget(c);
+    last_two
+}
+const CONST_ITER_BACK: (u32, u32) = const_iter_back_test();
+#[test]
+fn test_const_iter_back() {
+    assert_eq!(CONST_ITER_BACK, (5, 4));
+}
+
+#[test]
+fn test_iter_rev_clone() {
+    let c = ConstVec::<String, 4>::from_array(["a", "b", "c"].map(String::from));
+    assert!(c.iter().rev().eq(["c", "b", "a"].iter()));
+
+    // both ends meet in the middle
+    let mut iter = c.iter();
+    assert_eq!(iter.next_back().map(String::as_str), Some("c"));
+    let clone = iter.clone();
+    assert_eq!(iter.next().map(String::as_str), Some("a"));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back().map(String::as_str), Some("b"));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    assert_eq!(format!("{clone:?}"), r#"ConstVecIter(["a", "b"])"#);
+    assert_eq!(clone.count(), 2);
+}
+
+#[test]
+fn test_iter_matches_slice_iter() {
+    let c = ConstVec::<u32, 8>::from_array([1, 2, 3, 4, 5]);
+    let mut iter = c.iter();
+    let mut slice_iter = c.as_slice().iter();
+    iter.next();
+    slice_iter.next();
+    iter.next_back();
+    slice_iter.next_back();
+    assert_eq!(iter.as_slice(), slice_iter.as_slice());
+    assert!(iter.clone().eq(slice_iter.clone()));
+    assert!(iter.rev().eq(slice_iter.rev()));
+}
+
+#[test]
+fn test_collect() {
+    let exact: ConstVec<u32, 4> = (0..4).collect();
+    assert_eq!(exact, [0, 1, 2, 3]);
+
+    let under: ConstVec<u32, 4> = (0..2).collect();
+    assert_eq!(under, [0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "iterator yielded more than 4 items")]
+fn test_collect_overflow() {
+    let _: ConstVec<u32, 4> = (0..5).collect();
+}
+
+#[test]
+fn test_try_from_iter() {
+    assert_eq!(
+        ConstVec::<u32, 4>::try_from_iter(0..4).unwrap(),
+        [0, 1, 2, 3]
+    );
+
+    let mut source = 0..10;
+    let error = ConstVec::<u32, 4>::try_from_iter(&mut source).unwrap_err();
+    assert_eq!(error.vector, [0, 1, 2, 3]);
+    assert_eq!(error.rejected, 4);
+    assert!(source.eq(5..10));
+}
+
+#[test]
+fn test_from_iter_saturating() {
+    assert_eq!(
+        ConstVec::<u32, 4>::from_iter_saturating(0..100),
+        [0, 1, 2, 3]
+    );
+    assert_eq!(ConstVec::<u32, 4>::from_iter_saturating(0..2), [0, 1]);
+
+    let mut source = 0..10;
+    let _ = ConstVec::<u32, 4>::from_iter_saturating(&mut source);
+    assert!(source.eq(4..10));
+}
+
+#[test]
+fn test_extend() {
+    let mut c = ConstVec::<u32, 8>::from_array([1, 2, 3, 4]);
+    c.extend(5..9);
+    assert_eq!(c, [1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+#[should_panic(expected = "capacity 8 exceeded")]
+fn test_extend_overflow() {
+    let mut c = ConstVec::<u32, 8>::from_array([1, 2, 3, 4]);
+    c.extend(5..10);
+}
+
+#[test]
+fn test_position() {
+    let c = ConstVec::<u32, 8>::from_array([1, 2, 3, 4]);
+    assert_eq!(c.position(|&x| x > 2), Some(2));
+    assert_eq!(c.position(|&x| x > 4), None);
+    assert_eq!(c.iter().position(|&x| x > 2), Some(2));
+}
+
+#[test]
+fn test_count() {
+    let c = ConstVec::<u32, 8>::from_array([1, 2, 3, 4, 6]);
+    assert_eq!(c.count(|x| x % 2 == 0), 3);
+    assert_eq!(c.count(|&x| x > 10), 0);
+    assert_eq!(ConstVec::<u32, 8>::new().count(|_| true), 0);
+}
+
+#[test]
+fn test_exact_size_hint() {
+    let c = ConstVec::<String, 8>::from_array(["a", "b", "c"].map(String::from));
+
+    let mut iter = c.iter();
+    for remaining in (0..=3).rev() {
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+        iter.next();
+    }
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let mut into_iter = c.into_iter();
+    for remaining in (0..=3).rev() {
+        assert_eq!(into_iter.size_hint(), (remaining, Some(remaining)));
+        into_iter.next();
+    }
+    assert_eq!(into_iter.size_hint(), (0, Some(0)));
+}
+
+#[cfg(feature = "trusted-len")]
+#[test]
+fn test_trusted_len() {
+    fn assert_trusted_len<I: core::iter::TrustedLen>(_: &I) {}
+
+    let c = ConstVec::<u32, 8>::from_array([1, 2, 3]);
+    assert_trusted_len(&c.iter());
+    assert_trusted_len(&c.into_iter());
+}
+
+#[test]
+fn test_clone_into_iter() {
+    let c = ConstVec::<String, 8>::from_array(["a", "b", "c", "d"].map(String::from));
+    let mut iter = c.into_iter();
+    assert_eq!(iter.next().as_deref(), Some("a"));
+
+    let clone = iter.clone();
+    assert_eq!(clone.as_slice(), ["b", "c", "d"]);
+    assert_eq!(iter.next().as_deref(), Some("b"));
+    assert_eq!(iter.collect::<Vec<_>>(), ["c", "d"]);
+    assert_eq!(clone.collect::<Vec<_>>(), ["b", "c", "d"]);
+}
@@ -1,4 +1,4 @@
-use const_push::{constvec_by_array, ConstVec};
+use const_push::{constvec, constvec_by_array, constvec_concat, ConstVec};
 
 const fn construct_from_array() -> ConstVec<u32, 10> {
     ConstVec::from_array([10, 20, 30])
@@ -15,6 +15,13 @@ fn test_construct_from_array_exact() {
     assert_eq!(CONSTRUCT_FROM_ARRAY_EXACT.as_slice(), &[10, 20, 30])
 }
 
+const CONSTRUCT_FROM_ARRAY_EXACT_FULL: ConstVec<u32, 3> = ConstVec::from_array_exact([10, 20, 30]);
+#[test]
+fn test_construct_from_array_exact_full() {
+    assert_eq!(CONSTRUCT_FROM_ARRAY_EXACT_FULL.as_slice(), &[10, 20, 30]);
+    assert!(CONSTRUCT_FROM_ARRAY_EXACT_FULL.is_full());
+}
+
 const CONSTRUCT_FROM_DIRECT_ARRAY: ConstVec<u32, 10> = constvec_by_array![10, 20, 30,;..10];
 const CONSTRUCT_FROM_DIRECT_ARRAY_REPEATED: ConstVec<u32, 20> = constvec_by_array![99;10..20];
 #[test]
@@ -26,9 +33,199 @@ fn test_construct_from_array_repeated() {
     assert_eq!(CONSTRUCT_FROM_DIRECT_ARRAY_REPEATED.as_slice(), [99;10].as_slice())
 }
 
-// the below should not compile
-// const CONSTRUCT_TOO_LARGE: ConstVec<u32, 1> = ConstVec::from_array([10, 20]);
-// fn test_construct_too_large() {
-//     let _ = CONSTRUCT_TOO_LARGE;
-// }
-// const CONSTRUCT_INEXACT: ConstVec<u32, 5> = ConstVec::from_array_exact([10, 20, 30]);
+const CONSTRUCT_FROM_MACRO: ConstVec<u32, 10> = constvec![10, 20, 30; ..10];
+const CONSTRUCT_FROM_MACRO_REPEATED: ConstVec<u32, 20> = constvec![99; 10..20];
+#[test]
+fn test_construct_from_macro() {
+    assert_eq!(CONSTRUCT_FROM_MACRO, [10, 20, 30]);
+    assert_eq!(CONSTRUCT_FROM_MACRO_REPEATED, [99; 10]);
+}
+
+const CONSTRUCT_FROM_MACRO_EXACT: ConstVec<u32, 3> = constvec![10, 20, 30];
+#[test]
+fn test_construct_from_macro_exact() {
+    assert_eq!(CONSTRUCT_FROM_MACRO_EXACT, [10, 20, 30]);
+    // the capacity is inferred from the number of elements
+    let inferred = constvec!["a", "b",];
+    assert_eq!(inferred.capacity(), 2);
+    assert!(inferred.is_full());
+}
+
+const CONSTRUCT_EMPTY_FROM_MACRO: ConstVec<u32, 16> = constvec![; 16].push(1).push(2);
+const CONSTRUCT_EMPTY_FROM_MACRO_INFERRED: ConstVec<u32, 16> = constvec![];
+#[test]
+fn test_construct_empty_from_macro() {
+    assert_eq!(CONSTRUCT_EMPTY_FROM_MACRO, [1, 2]);
+    assert_eq!(CONSTRUCT_EMPTY_FROM_MACRO.capacity(), 16);
+    assert!(CONSTRUCT_EMPTY_FROM_MACRO_INFERRED.is_empty());
+}
+
+const CONCAT_HEAD: [u32; 2] = [1, 2];
+const CONCAT: ConstVec<u32, 10> = constvec_concat!(CONCAT_HEAD, [3, 4]; ..10);
+const CONCAT_FULL: ConstVec<u32, 4> = constvec_concat!([1], [2, 3], [], [4]; ..4);
+#[test]
+fn test_concat() {
+    assert_eq!(CONCAT, [1, 2, 3, 4]);
+    assert_eq!(CONCAT.capacity(), 10);
+    assert_eq!(CONCAT_FULL, [1, 2, 3, 4]);
+
+    let strings = constvec_concat!(["a".to_string()], ["b".to_string()]; ..4);
+    assert_eq!(strings.as_slice(), ["a", "b"]);
+}
+
+const fn upgrade() -> ConstVec<u32, 10> {
+    ConstVec::<u32, 3>::from_array([10, 20, 30]).upgrade()
+}
+const UPGRADE: ConstVec<u32, 10> = upgrade();
+#[test]
+fn test_upgrade() {
+    assert_eq!(UPGRADE.as_slice(), &[10, 20, 30]);
+    assert_eq!(UPGRADE.capacity(), 10);
+    assert_eq!(UPGRADE.push(40).as_slice(), &[10, 20, 30, 40]);
+}
+
+#[test]
+fn test_try_from_slice() {
+    let strings = ["a".to_string(), "b".to_string()];
+    let exact = ConstVec::<String, 2>::try_from_slice(&strings);
+    assert_eq!(exact.unwrap().as_slice(), ["a", "b"]);
+
+    let under = ConstVec::<u32, 4>::try_from_slice(&[10, 20]);
+    assert_eq!(under.unwrap().as_slice(), &[10, 20]);
+
+    let over = ConstVec::<u32, 2>::try_from_slice(&[10, 20, 30, 40]).unwrap_err();
+    assert_eq!(over.vector.as_slice(), &[10, 20]);
+    assert_eq!(over.rejected, &[30, 40]);
+}
+
+const fn checked_table<const N: usize>() -> ConstVec<u32, 8> {
+    ConstVec::<u32, 8>::assert_fits::<N>();
+    ConstVec::filled(1, N)
+}
+const CHECKED_TABLE: ConstVec<u32, 8> = checked_table::<8>();
+#[test]
+fn test_assert_fits() {
+    assert_eq!(CHECKED_TABLE, [1; 8]);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // trybuild runs cargo, which miri can't do
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
+
+const INTO_ARRAY: Result<[u32; 3], ConstVec<u32, 3>> =
+    ConstVec::from_array_exact([10, 20, 30]).try_into_array();
+const INTO_ARRAY_NOT_FULL: Result<[u32; 3], ConstVec<u32, 3>> =
+    ConstVec::from_array([10, 20]).try_into_array();
+#[test]
+fn test_try_into_array() {
+    assert_eq!(INTO_ARRAY.unwrap(), [10, 20, 30]);
+    assert_eq!(INTO_ARRAY_NOT_FULL.unwrap_err().as_slice(), &[10, 20]);
+
+    let strings = ConstVec::<String, 2>::new()
+        .push("a".to_string())
+        .push("b".to_string());
+    assert_eq!(strings.try_into_array().unwrap(), ["a", "b"]);
+}
+
+const FROM_ARRAY_REV: ConstVec<u32, 4> = ConstVec::from_array_rev([1, 2, 3]);
+#[test]
+fn test_from_array_rev() {
+    assert_eq!(FROM_ARRAY_REV, [3, 2, 1]);
+    assert_eq!(FROM_ARRAY_REV.capacity(), 4);
+
+    let strings = ConstVec::<String, 2>::from_array_rev(["a", "b"].map(String::from));
+    assert_eq!(strings.as_slice(), ["b", "a"]);
+}
+
+const FILLED: ConstVec<u32, 6> = ConstVec::filled(7, 4);
+const FILLED_EMPTY: ConstVec<u32, 6> = ConstVec::filled(7, 0);
+#[test]
+fn test_filled() {
+    assert_eq!(FILLED, [7, 7, 7, 7]);
+    assert!(FILLED_EMPTY.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "fill length is greater than the capacity")]
+fn test_filled_past_capacity() {
+    let _ = ConstVec::<u32, 2>::filled(7, 3);
+}
+
+const JOINED: ConstVec<u32, 4> = ConstVec::join_with(&[&[1, 2], &[3]], 0);
+const JOINED_BYTES: ConstVec<u8, 16> = ConstVec::join_with(&[b"usr", b"local", b"bin"], b'/');
+#[test]
+fn test_join_with() {
+    assert_eq!(JOINED, [1, 2, 0, 3]);
+    assert_eq!(JOINED_BYTES.as_slice(), b"usr/local/bin");
+    assert!(ConstVec::<u32, 4>::join_with(&[], 0).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "the joined elements exceed the capacity")]
+fn test_join_with_past_capacity() {
+    let _ = ConstVec::<u32, 3>::join_with(&[&[1, 2], &[3]], 0);
+}
+
+const REPEATED: ConstVec<u32, 6> = ConstVec::<u32, 4>::from_array([1, 2]).repeated();
+const REPEATED_UNEVEN: ConstVec<u32, 7> = ConstVec::<u32, 4>::from_array([1, 2]).repeated();
+const REPEATED_EMPTY: ConstVec<u32, 6> = ConstVec::<u32, 4>::new().repeated();
+#[test]
+fn test_repeated() {
+    assert_eq!(REPEATED, [1, 2, 1, 2, 1, 2]);
+    // only whole copies are added
+    assert_eq!(REPEATED_UNEVEN, [1, 2, 1, 2, 1, 2]);
+    assert!(REPEATED_EMPTY.is_empty());
+    // the slice method is still reachable
+    assert_eq!(
+        ConstVec::<u32, 4>::from_array([1, 2]).repeat(2),
+        [1, 2, 1, 2]
+    );
+}
+
+#[test]
+#[should_panic(expected = "the elements don't fit in the new capacity")]
+fn test_repeated_past_capacity() {
+    let _ = ConstVec::<u32, 4>::from_array([1, 2, 3]).repeated::<2>();
+}
+
+const FROM_STR: ConstVec<u8, 8> = ConstVec::from_str("hi");
+const FROM_STR_FULL: ConstVec<u8, 3> = ConstVec::from_str("h\u{e9}");
+#[test]
+fn test_from_str() {
+    assert_eq!(FROM_STR, [b'h', b'i']);
+    assert_eq!(FROM_STR_FULL, [b'h', 0xc3, 0xa9]);
+    assert!(ConstVec::<u8, 0>::from_str("").is_empty());
+}
+
+#[test]
+fn test_as_str() {
+    let c = ConstVec::<u8, 8>::from_str("abc");
+    assert_eq!(c.as_str(), Ok("abc"));
+    assert_eq!(unsafe { c.as_str_unchecked() }, "abc");
+
+    let invalid = ConstVec::<u8, 8>::from_array([b'a', 0xff]);
+    assert_eq!(invalid.as_str().unwrap_err().valid_up_to(), 1);
+}
+
+const fn squares() -> [u32; 256] {
+    let mut xs = [0; 256];
+    let mut ix = 0;
+    while ix < 256 {
+        xs[ix] = (ix * ix) as u32;
+        ix += 1;
+    }
+    xs
+}
+
+const CONSTRUCT_LARGE: ConstVec<u32, 300> = ConstVec::from_array(squares());
+#[test]
+fn test_construct_large() {
+    assert_eq!(CONSTRUCT_LARGE.len(), 256);
+    assert_eq!(CONSTRUCT_LARGE, squares());
+    assert_eq!(
+        ConstVec::<u32, 256>::from_array_exact(squares()).upgrade::<512>(),
+        squares()
+    );
+}
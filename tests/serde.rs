@@ -0,0 +1,27 @@
+#![cfg(feature = "serde")]
+
+use const_push::ConstVec;
+
+const SERDE: ConstVec<u32, 8> = ConstVec::new().push(10).push(20).push(30);
+#[test]
+fn test_serde_round_trip() {
+    let json = serde_json::to_string(&SERDE).unwrap();
+    assert_eq!(json, "[10,20,30]");
+
+    let deserialized: ConstVec<u32, 8> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, SERDE);
+
+    let strings: ConstVec<String, 2> = serde_json::from_str(r#"["a","b"]"#).unwrap();
+    assert_eq!(strings.as_slice(), ["a", "b"]);
+}
+
+#[test]
+fn test_deserialize_overflow() {
+    let error = serde_json::from_str::<ConstVec<u32, 2>>("[1,2,3]").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .starts_with("invalid length 3, expected a sequence of at most 2 elements"),
+        "unexpected error: {error}"
+    );
+}
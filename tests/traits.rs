@@ -0,0 +1,291 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use const_push::ConstVec;
+
+#[test]
+fn test_clone_independent() {
+    let original = ConstVec::<String, 8>::new()
+        .push("a".to_string())
+        .push("b".to_string());
+    let clone = original.clone().push("c".to_string());
+
+    assert_eq!(original.as_slice(), ["a", "b"]);
+    assert_eq!(clone.as_slice(), ["a", "b", "c"]);
+}
+
+static CLONE_PANIC_DROPS: AtomicUsize = AtomicUsize::new(0);
+
+/// Panics when cloned if `fail` is set, and counts how many times it is dropped
+struct FallibleClone {
+    fail: bool,
+}
+
+impl Clone for FallibleClone {
+    fn clone(&self) -> Self {
+        assert!(!self.fail, "clone failed");
+        Self { fail: false }
+    }
+}
+
+impl Drop for FallibleClone {
+    fn drop(&mut self) {
+        CLONE_PANIC_DROPS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_clone_panic_drops_cloned() {
+    let original = ConstVec::<_, 4>::new()
+        .push(FallibleClone { fail: false })
+        .push(FallibleClone { fail: false })
+        .push(FallibleClone { fail: true });
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| original.clone()));
+    assert!(result.is_err());
+    // the two elements cloned before the panic have been dropped
+    assert_eq!(CLONE_PANIC_DROPS.load(Ordering::SeqCst), 2);
+
+    drop(original);
+    assert_eq!(CLONE_PANIC_DROPS.load(Ordering::SeqCst), 5);
+}
+
+const EQ_SMALL: ConstVec<u32, 4> = ConstVec::new().push(10).push(20).push(30);
+const EQ_LARGE: ConstVec<u32, 8> = ConstVec::new().push(10).push(20).push(30);
+#[test]
+fn test_eq_across_capacities() {
+    assert_eq!(EQ_SMALL, EQ_LARGE);
+    assert_ne!(EQ_SMALL, EQ_LARGE.push(40));
+}
+
+#[test]
+fn test_eq_slice_and_array() {
+    assert_eq!(EQ_SMALL, [10, 20, 30]);
+    assert_eq!(EQ_SMALL, *[10, 20, 30].as_slice());
+    assert_ne!(EQ_SMALL, [10, 20]);
+    assert_ne!(EQ_SMALL, *[10, 20, 31].as_slice());
+
+    // and the other way around
+    assert_eq!([10, 20, 30], EQ_SMALL);
+    assert_eq!(*[10, 20, 30].as_slice(), EQ_SMALL);
+    assert_ne!([10, 20, 30, 0], EQ_SMALL);
+    assert_ne!(*[10, 20].as_slice(), EQ_SMALL);
+}
+
+const ORD_12: ConstVec<u32, 4> = ConstVec::new().push(1).push(2);
+const ORD_123: ConstVec<u32, 4> = ConstVec::new().push(1).push(2).push(3);
+const ORD_13: ConstVec<u32, 4> = ConstVec::new().push(1).push(3);
+#[test]
+fn test_ord_lexicographic() {
+    assert!(ORD_12 < ORD_123);
+    assert!(ORD_123 < ORD_13);
+
+    let mut sorted = [ORD_13, ORD_123, ORD_12];
+    sorted.sort();
+    assert_eq!(sorted, [ORD_12, ORD_123, ORD_13]);
+}
+
+#[test]
+fn test_partial_ord_across_capacities() {
+    assert!(ORD_12 < EQ_LARGE);
+    assert_eq!(
+        EQ_SMALL.partial_cmp(&EQ_LARGE),
+        Some(std::cmp::Ordering::Equal)
+    );
+}
+
+#[test]
+fn test_hash_across_capacities() {
+    use std::hash::{BuildHasher, RandomState};
+
+    let hasher = RandomState::new();
+    assert_eq!(hasher.hash_one(EQ_SMALL), hasher.hash_one(EQ_LARGE));
+    assert_eq!(
+        hasher.hash_one(EQ_SMALL),
+        hasher.hash_one([10u32, 20, 30].as_slice())
+    );
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(EQ_SMALL);
+    assert!(set.contains(&ConstVec::new().push(10).push(20).push(30)));
+}
+
+const DEREF: ConstVec<u32, 8> = ConstVec::new().push(10).push(20).push(30).push(40);
+// inherent methods still take precedence over the slice methods, so these stay const
+const fn deref_len(v: &ConstVec<u32, 8>) -> (usize, bool, Option<&u32>) {
+    (v.len(), v.is_empty(), v.get(1))
+}
+#[test]
+fn test_deref_slice_methods() {
+    assert_eq!(deref_len(&DEREF), (4, false, Some(&20)));
+    assert!(DEREF.contains(&30));
+    assert_eq!(DEREF.iter().sum::<u32>(), 100);
+    assert_eq!(&DEREF[1..3], &[20, 30]);
+    assert_eq!(DEREF.first(), Some(&10));
+    assert_eq!(DEREF.get(4), None);
+}
+
+#[test]
+fn test_deref_mut() {
+    let mut v = DEREF;
+    v.sort_by(|a, b| b.cmp(a));
+    v[0] += 1;
+    assert_eq!(v, [41, 30, 20, 10]);
+}
+
+#[test]
+fn test_contains_through_deref() {
+    let c = ConstVec::<u32, 8>::from_array([3, 1, 4, 1, 5]);
+    assert!(c.contains(&4));
+    assert!(!c.contains(&2));
+    // slots past the live prefix are never searched
+    assert!(!ConstVec::<u32, 8>::new().contains(&0));
+}
+
+#[test]
+fn test_binary_search_through_deref() {
+    // spare capacity holds nothing, so a miss past the end points at `len`
+    let c = ConstVec::<u32, 8>::from_array([10, 20, 30, 40]);
+    assert_eq!(c.binary_search(&30), Ok(2));
+    assert_eq!(c.binary_search(&25), Err(2));
+    assert_eq!(c.binary_search(&50), Err(4));
+    assert_eq!(c.binary_search_by(|x| x.cmp(&10)), Ok(0));
+}
+
+#[test]
+fn test_starts_ends_with_through_deref() {
+    let c = ConstVec::<u8, 8>::from_array(*b"key=val");
+    assert!(c.starts_with(b"key="));
+    assert!(!c.starts_with(b"val"));
+    assert!(c.ends_with(b"=val"));
+    assert!(!c.ends_with(b"key"));
+    // needles longer than the live prefix never match, even if they fit the capacity
+    assert!(!c.starts_with(b"key=val!"));
+}
+
+#[test]
+fn test_windows_chunks_through_deref() {
+    let c = ConstVec::<u32, 8>::from_array([1, 2, 3]);
+    let windows: Vec<_> = c.windows(2).collect();
+    assert_eq!(windows, [[1, 2], [2, 3]]);
+    let chunks: Vec<_> = c.chunks(2).collect();
+    assert_eq!(chunks, [&[1, 2][..], &[3]]);
+}
+
+#[test]
+fn test_fill_through_deref() {
+    // only the live elements are overwritten
+    let mut c = ConstVec::<String, 8>::from_array(["a", "b", "c"].map(String::from));
+    c.fill("x".to_string());
+    assert_eq!(c.as_slice(), ["x", "x", "x"]);
+
+    let mut next = 0;
+    c.fill_with(|| {
+        next += 1;
+        next.to_string()
+    });
+    assert_eq!(c.as_slice(), ["1", "2", "3"]);
+}
+
+#[test]
+fn test_copy_within_through_deref() {
+    let mut c = ConstVec::<u32, 8>::from_array([1, 2, 3, 4, 5]);
+    c.copy_within(0..2, 3);
+    assert_eq!(c, [1, 2, 3, 1, 2]);
+
+    let mut other = [9, 9];
+    c[..2].swap_with_slice(&mut other);
+    assert_eq!(c, [9, 9, 3, 1, 2]);
+    assert_eq!(other, [1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "dest is out of bounds")]
+fn test_copy_within_past_len() {
+    // the spare capacity isn't part of the slice
+    let mut c = ConstVec::<u32, 8>::from_array([1, 2, 3, 4, 5]);
+    c.copy_within(0..2, 4);
+}
+
+#[test]
+fn test_default() {
+    #[derive(Default)]
+    struct Table {
+        entries: ConstVec<u32, 4>,
+    }
+
+    assert!(ConstVec::<u32, 4>::default().is_empty());
+    assert!(Table::default().entries.is_empty());
+}
+
+#[test]
+fn test_capacity_error_display() {
+    let error = ConstVec::<u32, 1>::new().push(1).try_push(2).unwrap_err();
+    assert_eq!(error.to_string(), "capacity 1 exceeded");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_capacity_error_boxed() {
+    use std::error::Error;
+
+    fn push_twice() -> Result<ConstVec<u32, 1>, Box<dyn Error>> {
+        Ok(ConstVec::new().try_push(1)?.try_push(2)?)
+    }
+
+    let error = push_twice().unwrap_err();
+    assert_eq!(error.to_string(), "capacity 1 exceeded");
+}
+
+#[test]
+fn test_as_ref_slice() {
+    fn sum(xs: impl AsRef<[u32]>) -> u32 {
+        xs.as_ref().iter().sum()
+    }
+    fn zero_first(mut xs: impl AsMut<[u32]>) -> u32 {
+        xs.as_mut()[0] = 0;
+        xs.as_mut().iter().sum()
+    }
+
+    assert_eq!(sum(EQ_SMALL), 60);
+    assert_eq!(zero_first(EQ_SMALL), 50);
+}
+
+#[test]
+fn test_borrow_slice() {
+    use std::borrow::BorrowMut;
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(EQ_SMALL);
+    assert!(set.contains([10, 20, 30].as_slice()));
+
+    let mut c = EQ_SMALL;
+    let xs: &mut [u32] = c.borrow_mut();
+    xs[2] = 0;
+    assert_eq!(c, [10, 20, 0]);
+}
+
+#[test]
+fn test_index() {
+    let mut c = EQ_LARGE;
+    assert_eq!(c[2], 30);
+    c[2] = 31;
+    assert_eq!(c[2], 31);
+    assert_eq!(c[1..], [20, 31]);
+    assert_eq!(c[..=1], [10, 20]);
+}
+
+#[test]
+#[should_panic(expected = "the len is 3 but the index is 3")]
+fn test_index_past_len() {
+    // there is room for the index, but it isn't initialized
+    let _ = EQ_LARGE[3];
+}
+
+// actually logging needs a global logger, so only check that the impl exists
+#[cfg(feature = "defmt")]
+#[test]
+fn test_defmt_format() {
+    fn assert_format<T: defmt::Format>() {}
+    assert_format::<ConstVec<u32, 4>>();
+    assert_format::<ConstVec<ConstVec<u8, 2>, 4>>();
+}
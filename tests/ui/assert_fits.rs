@@ -0,0 +1,12 @@
+use const_push::ConstVec;
+
+const fn table<const N: usize>() -> ConstVec<u32, 4> {
+    ConstVec::<u32, 4>::assert_fits::<N>();
+    ConstVec::new()
+}
+
+const TABLE: ConstVec<u32, 4> = table::<5>();
+
+fn main() {
+    let _ = TABLE;
+}
@@ -0,0 +1,9 @@
+use const_push::{constvec, constvec_by_array, ConstVec};
+
+const REPEATED_TOO_LARGE: ConstVec<u32, 20> = constvec_by_array![99; 30..20];
+const REPEATED_TOO_LARGE_MACRO: ConstVec<u32, 2> = constvec![0; 3..2];
+
+fn main() {
+    let _ = REPEATED_TOO_LARGE;
+    let _ = REPEATED_TOO_LARGE_MACRO;
+}
@@ -0,0 +1,7 @@
+use const_push::{constvec_concat, ConstVec};
+
+const CONCAT_TOO_LARGE: ConstVec<u32, 3> = constvec_concat!([1, 2], [3, 4]; ..3);
+
+fn main() {
+    let _ = CONCAT_TOO_LARGE;
+}
@@ -0,0 +1,7 @@
+use const_push::ConstVec;
+
+const CONSTRUCT_INEXACT: ConstVec<u32, 3> = ConstVec::from_array_exact([10, 20, 30, 40, 50]);
+
+fn main() {
+    let _ = CONSTRUCT_INEXACT;
+}
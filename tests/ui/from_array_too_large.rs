@@ -0,0 +1,9 @@
+use const_push::{constvec_by_array, ConstVec};
+
+const CONSTRUCT_TOO_LARGE: ConstVec<u32, 1> = ConstVec::from_array([10, 20]);
+const CONSTRUCT_TOO_LARGE_MACRO: ConstVec<u32, 2> = constvec_by_array![10, 20, 30; ..2];
+
+fn main() {
+    let _ = CONSTRUCT_TOO_LARGE;
+    let _ = CONSTRUCT_TOO_LARGE_MACRO;
+}
@@ -0,0 +1,7 @@
+use const_push::ConstVec;
+
+const TOO_LONG: ConstVec<u8, 4> = ConstVec::from_str("hello");
+
+fn main() {
+    let _ = TOO_LONG;
+}
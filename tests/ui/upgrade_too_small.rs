@@ -0,0 +1,7 @@
+use const_push::ConstVec;
+
+const UPGRADE_TOO_SMALL: ConstVec<u32, 2> = ConstVec::<u32, 3>::new().upgrade();
+
+fn main() {
+    let _ = UPGRADE_TOO_SMALL;
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "fake-move")]
+
+use const_push::ConstVec;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Unit;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(align(32))]
+struct Aligned;
+
+const UNITS: ConstVec<(), 4> = ConstVec::new().push(()).push(()).push(());
+#[test]
+fn test_unit() {
+    assert_eq!(UNITS.len(), 3);
+    assert_eq!(UNITS.as_slice(), [(), (), ()]);
+    assert_eq!(UNITS.get(2), Some(&()));
+    assert_eq!(UNITS.get(3), None);
+    assert_eq!(UNITS.iter().count(), 3);
+    assert_eq!(UNITS.into_iter().count(), 3);
+
+    let (c, popped) = UNITS.pop();
+    assert_eq!(popped, Some(()));
+    assert_eq!(c.len(), 2);
+}
+
+const UNIT_STRUCTS: ConstVec<Unit, 4> = ConstVec::new().push(Unit).push(Unit);
+#[test]
+fn test_unit_struct() {
+    let c = UNIT_STRUCTS.push(Unit);
+    assert_eq!(c.len(), 3);
+    assert!(!c.is_full());
+    assert_eq!(c.iter().collect::<Vec<_>>(), [&Unit; 3]);
+
+    let (c, popped) = c.try_remove(0);
+    assert_eq!(popped, Some(Unit));
+    assert_eq!(c, [Unit, Unit]);
+    assert_eq!(c.try_into_array(), Err(ConstVec::from_array([Unit, Unit])));
+}
+
+#[test]
+fn test_full_zst() {
+    let c = ConstVec::<(), 2>::new().push(()).push(());
+    assert!(c.is_full());
+    assert_eq!(c.try_push(()).unwrap_err().vector.len(), 2);
+    assert_eq!(
+        ConstVec::<(), 2>::from_array_exact([(), ()]).try_into_array(),
+        Ok([(), ()])
+    );
+}
+
+// the buffer starts after `len`, so elements aligned past `usize` mustn't be read from its end
+#[test]
+fn test_overaligned() {
+    let c = ConstVec::<Aligned, 4>::new().push(Aligned).push(Aligned);
+    assert_eq!(c.as_ptr() as usize % 32, 0);
+    assert_eq!(c.as_slice(), [Aligned, Aligned]);
+    let (c, popped) = c.pop();
+    assert_eq!(popped, Some(Aligned));
+    assert_eq!(c.into_iter().count(), 1);
+
+    let c = ConstVec::<u128, 4>::from_array([5, 6]);
+    assert_eq!(c.as_slice(), [5, 6]);
+    assert_eq!(c.pop().1, Some(6));
+}